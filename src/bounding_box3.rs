@@ -0,0 +1,1048 @@
+/*!
+Defines the three-dimensional [`BoundingBox3`] and the accompanying
+[`ToBoundingBox3`] trait.
+ */
+
+#[cfg(feature = "approx")]
+use approx::{UlpsEq, ulps_eq};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::Scalar;
+
+/**
+A rectilinear, 3-dimensional bounding box, also known as axis-aligned bounding
+box (AABB).
+
+This is the 3-dimensional counterpart of [`BoundingBox`](crate::BoundingBox)
+and offers the same API, extended by a third (z-)dimension. It is described by
+six values: minimum and maximum x-value, minimum and maximum y-value and minimum
+and maximum z-value. As with the 2-dimensional box, these values are called
+"extremas" and the scalar type `T` defaults to `f64`.
+
+# NaN policy
+
+The [NaN policy](crate::BoundingBox#nan-policy) of the 2-dimensional box applies
+as well: The constructors reject NaN values and
+[`from_points`](BoundingBox3::from_points) and
+[`from_bounded_entities`](BoundingBox3::from_bounded_entities) skip points /
+boxes containing NaN. [`touches`](BoundingBox3::touches) and
+`approx_touches` return `false` if any extremum of either box is NaN, like
+the other predicates.
+
+# Features
+
+This struct can be serialized / deserialized if the `serde` feature is enabled.
+Deserialization validates the extremas like [`try_new`](BoundingBox3::try_new)
+does.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct BoundingBox3<T = f64> {
+    xmin: T,
+    xmax: T,
+    ymin: T,
+    ymax: T,
+    zmin: T,
+    zmax: T,
+}
+
+impl<T: Scalar> BoundingBox3<T> {
+    /**
+    Generates a bounding box from minimum and maximum x-, y- and z-values.
+
+    # Panics
+    Panics if `xmin > xmax`, `ymin > ymax` or `zmin > zmax` or if any of the
+    values is NaN.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox3;
+
+    let _ = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    ```
+
+    This example panics because `zmin > zmax`.
+
+    ```should_panic
+    use bounding_box::BoundingBox3;
+
+    let _ = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 2.0, 1.0);
+    ```
+     */
+    pub fn new(xmin: T, xmax: T, ymin: T, ymax: T, zmin: T, zmax: T) -> Self {
+        return Self::try_new(xmin, xmax, ymin, ymax, zmin, zmax).expect(
+            "one of the conditions xmin <= xmax, ymin <= ymax and zmin <= zmax is not fulfilled or an extremum is NaN",
+        );
+    }
+
+    /**
+    Like [`BoundingBox3::new`], but returns `None` instead of panicking if
+    `xmin > xmax`, `ymin > ymax` or `zmin > zmax` or if any of the values is
+    NaN.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox3;
+
+    assert!(BoundingBox3::try_new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0).is_some());
+    assert!(BoundingBox3::try_new(0.0, 1.0, 0.0, 1.0, 2.0, 1.0).is_none());
+    assert!(BoundingBox3::try_new(f64::NAN, 1.0, 0.0, 1.0, 0.0, 1.0).is_none());
+    ```
+     */
+    pub fn try_new(xmin: T, xmax: T, ymin: T, ymax: T, zmin: T, zmax: T) -> Option<Self> {
+        // Written as negated `<=` so that comparisons involving NaN fail
+        if !(xmin <= xmax && ymin <= ymax && zmin <= zmax) {
+            return None;
+        }
+        return Some(BoundingBox3 {
+            xmin,
+            xmax,
+            ymin,
+            ymax,
+            zmin,
+            zmax,
+        });
+    }
+
+    /**
+    Returns the minimum x-value of the bounding box.
+     */
+    pub fn xmin(&self) -> T {
+        return self.xmin;
+    }
+
+    /**
+    Returns the maximum x-value of the bounding box.
+     */
+    pub fn xmax(&self) -> T {
+        return self.xmax;
+    }
+
+    /**
+    Returns the minimum y-value of the bounding box.
+     */
+    pub fn ymin(&self) -> T {
+        return self.ymin;
+    }
+
+    /**
+    Returns the maximum y-value of the bounding box.
+     */
+    pub fn ymax(&self) -> T {
+        return self.ymax;
+    }
+
+    /**
+    Returns the minimum z-value of the bounding box.
+     */
+    pub fn zmin(&self) -> T {
+        return self.zmin;
+    }
+
+    /**
+    Returns the maximum z-value of the bounding box.
+     */
+    pub fn zmax(&self) -> T {
+        return self.zmax;
+    }
+
+    /**
+    Fallible sets a new value for `xmin`. If the new value is bigger than `xmax` or NaN, the old value is left
+    unchanged and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox3;
+
+    let mut bb = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.try_set_xmin(0.5));
+    assert_eq!(bb.xmin(), 0.5);
+
+    assert!(!bb.try_set_xmin(1.5));
+    assert!(!bb.try_set_xmin(f64::NAN));
+    assert_eq!(bb.xmin(), 0.5);
+    ```
+     */
+    pub fn try_set_xmin(&mut self, val: T) -> bool {
+        if val.is_nan() || val > self.xmax {
+            return false;
+        }
+        self.xmin = val;
+        return true;
+    }
+
+    /**
+    Fallible sets a new value for `xmax`. If the new value is smaller than `xmin` or NaN, the old value is left
+    unchanged and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox3;
+
+    let mut bb = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.try_set_xmax(0.5));
+    assert_eq!(bb.xmax(), 0.5);
+
+    assert!(!bb.try_set_xmax(-0.5));
+    assert!(!bb.try_set_xmax(f64::NAN));
+    assert_eq!(bb.xmax(), 0.5);
+    ```
+     */
+    pub fn try_set_xmax(&mut self, val: T) -> bool {
+        if val.is_nan() || val < self.xmin {
+            return false;
+        }
+        self.xmax = val;
+        return true;
+    }
+
+    /**
+    Fallible sets a new value for `ymin`. If the new value is bigger than `ymax` or NaN, the old value is left
+    unchanged and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox3;
+
+    let mut bb = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.try_set_ymin(0.5));
+    assert_eq!(bb.ymin(), 0.5);
+
+    assert!(!bb.try_set_ymin(1.5));
+    assert!(!bb.try_set_ymin(f64::NAN));
+    assert_eq!(bb.ymin(), 0.5);
+    ```
+     */
+    pub fn try_set_ymin(&mut self, val: T) -> bool {
+        if val.is_nan() || val > self.ymax {
+            return false;
+        }
+        self.ymin = val;
+        return true;
+    }
+
+    /**
+    Fallible sets a new value for `ymax`. If the new value is smaller than `ymin` or NaN, the old value is left
+    unchanged and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox3;
+
+    let mut bb = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.try_set_ymax(0.5));
+    assert_eq!(bb.ymax(), 0.5);
+
+    assert!(!bb.try_set_ymax(-0.5));
+    assert!(!bb.try_set_ymax(f64::NAN));
+    assert_eq!(bb.ymax(), 0.5);
+    ```
+     */
+    pub fn try_set_ymax(&mut self, val: T) -> bool {
+        if val.is_nan() || val < self.ymin {
+            return false;
+        }
+        self.ymax = val;
+        return true;
+    }
+
+    /**
+    Fallible sets a new value for `zmin`. If the new value is bigger than `zmax` or NaN, the old value is left
+    unchanged and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox3;
+
+    let mut bb = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.try_set_zmin(0.5));
+    assert_eq!(bb.zmin(), 0.5);
+
+    assert!(!bb.try_set_zmin(1.5));
+    assert!(!bb.try_set_zmin(f64::NAN));
+    assert_eq!(bb.zmin(), 0.5);
+    ```
+     */
+    pub fn try_set_zmin(&mut self, val: T) -> bool {
+        if val.is_nan() || val > self.zmax {
+            return false;
+        }
+        self.zmin = val;
+        return true;
+    }
+
+    /**
+    Fallible sets a new value for `zmax`. If the new value is smaller than `zmin` or NaN, the old value is left
+    unchanged and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox3;
+
+    let mut bb = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.try_set_zmax(0.5));
+    assert_eq!(bb.zmax(), 0.5);
+
+    assert!(!bb.try_set_zmax(-0.5));
+    assert!(!bb.try_set_zmax(f64::NAN));
+    assert_eq!(bb.zmax(), 0.5);
+    ```
+     */
+    pub fn try_set_zmax(&mut self, val: T) -> bool {
+        if val.is_nan() || val < self.zmin {
+            return false;
+        }
+        self.zmax = val;
+        return true;
+    }
+
+    /**
+    Creates a bounding box from an iterator over vertices.
+
+    Points with a NaN coordinate are skipped. If the iterator is empty or only
+    yields such points, this function returns `None`.
+    ```
+    use bounding_box::BoundingBox3;
+
+    let verts = vec![
+        [1.0, 0.0, 2.0],
+        [-5.0, 2.0, 0.0],
+        [3.0, -12.3, -1.0],
+        [7.0, 0.0, 4.0],
+    ];
+
+    let bb = BoundingBox3::from_points(verts.into_iter()).expect("iterator yields at least one elment");
+    assert_eq!(bb.xmin(), -5.0);
+    assert_eq!(bb.xmax(), 7.0);
+    assert_eq!(bb.ymin(), -12.3);
+    assert_eq!(bb.ymax(), 2.0);
+    assert_eq!(bb.zmin(), -1.0);
+    assert_eq!(bb.zmax(), 4.0);
+
+    // Points with NaN coordinates are skipped
+    let verts = vec![[f64::NAN, 0.0, 0.0], [1.0, 1.0, 1.0], [0.0, 0.0, f64::NAN]];
+    let bb = BoundingBox3::from_points(verts.into_iter()).unwrap();
+    assert_eq!(bb, BoundingBox3::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0));
+    ```
+     */
+    pub fn from_points<P: Into<[T; 3]>, I: Iterator<Item = P>>(verts: I) -> Option<Self> {
+        let has_nan = |pt: &[T; 3]| pt.iter().any(|val| val.is_nan());
+        let mut verts = verts.map(Into::into).filter(|pt| !has_nan(pt));
+        let pt: [T; 3] = verts.next()?;
+        let mut bb = BoundingBox3 {
+            xmin: pt[0],
+            xmax: pt[0],
+            ymin: pt[1],
+            ymax: pt[1],
+            zmin: pt[2],
+            zmax: pt[2],
+        };
+        for pt in verts {
+            if pt[0] > bb.xmax {
+                bb.xmax = pt[0]
+            }
+            if pt[0] < bb.xmin {
+                bb.xmin = pt[0]
+            }
+            if pt[1] > bb.ymax {
+                bb.ymax = pt[1]
+            }
+            if pt[1] < bb.ymin {
+                bb.ymin = pt[1]
+            }
+            if pt[2] > bb.zmax {
+                bb.zmax = pt[2]
+            }
+            if pt[2] < bb.zmin {
+                bb.zmin = pt[2]
+            }
+        }
+        return Some(bb);
+    }
+
+    /**
+    Creates a bounding box from an iterator over any types implementing
+    [`Into<BoundingBox3>`].
+
+    Boxes with a NaN extremum are skipped. If the iterator is empty or only
+    yields such boxes, this function returns `None`.
+
+    ```
+    use bounding_box::{BoundingBox3, ToBoundingBox3};
+
+    struct Sphere {
+        center: [f64; 3],
+        radius: f64
+    }
+
+    impl ToBoundingBox3 for Sphere {
+        fn bounding_box3(&self) -> BoundingBox3 {
+            return BoundingBox3::new(self.center[0] - self.radius,
+                                     self.center[0] + self.radius,
+                                     self.center[1] - self.radius,
+                                     self.center[1] + self.radius,
+                                     self.center[2] - self.radius,
+                                     self.center[2] + self.radius);
+        }
+    }
+
+    let s1 = Sphere {center: [0.0, 0.0, 0.0], radius: 1.0};
+    let s2 = Sphere {center: [0.0, 2.0, 1.0], radius: 2.0};
+
+    let bb = BoundingBox3::from_bounded_entities([&s1, &s2].into_iter()).expect("iterator has at least one element");
+    assert_eq!(bb, BoundingBox3::new(-2.0, 2.0, -1.0, 4.0, -1.0, 3.0));
+    ```
+     */
+    pub fn from_bounded_entities<E: Into<BoundingBox3<T>>, I: Iterator<Item = E>>(
+        entities: I,
+    ) -> Option<Self> {
+        return entities
+            .map(Into::into)
+            .filter(|bb: &BoundingBox3<T>| !bb.has_nan())
+            .reduce(|acc, bb| bb.union(&acc));
+    }
+
+    /// Returns true if any of the extremas is NaN. This can only happen if a
+    /// transformation (e.g. [`BoundingBox3::translate`]) is applied with NaN
+    /// arguments or results in an undefined operation such as `inf - inf`.
+    fn has_nan(&self) -> bool {
+        return [
+            self.xmin, self.xmax, self.ymin, self.ymax, self.zmin, self.zmax,
+        ]
+        .iter()
+        .any(|val| val.is_nan());
+    }
+
+    /**
+    Creates the union of two bounding boxes.
+
+    The union of two bounding boxes is the minimum bounding box which covers
+    both bounding boxes.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb1 = BoundingBox3::new(-1.0, 3.5, 2.0, 3.0, 0.0, 1.0);
+    let bb2 = BoundingBox3::new(-5.0, 2.5, -1.0, 5.0, 0.5, 2.0);
+    assert_eq!(bb1.union(&bb2), BoundingBox3::new(-5.0, 3.5, -1.0, 5.0, 0.0, 2.0));
+    ```
+    */
+    pub fn union(&self, other: &BoundingBox3<T>) -> BoundingBox3<T> {
+        let min = |a: T, b: T| if a > b { b } else { a };
+        let max = |a: T, b: T| if a > b { a } else { b };
+        return BoundingBox3 {
+            xmin: min(self.xmin, other.xmin),
+            xmax: max(self.xmax, other.xmax),
+            ymin: min(self.ymin, other.ymin),
+            ymax: max(self.ymax, other.ymax),
+            zmin: min(self.zmin, other.zmin),
+            zmax: max(self.zmax, other.zmax),
+        };
+    }
+
+    /**
+    Returns true if `self` covers a given point, i.e. if the point is either
+    within or on the boundaries of `self`.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.covers_point([0.5, 0.5, 0.5]));
+    assert!(bb.covers_point([0.0, 0.0, 1.0])); // On boundary
+    assert!(!bb.covers_point([0.5, 0.5, 2.0]));
+    ```
+     */
+    pub fn covers_point<P: Into<[T; 3]>>(&self, point: P) -> bool {
+        let point: [T; 3] = point.into();
+        return self.xmin <= point[0]
+            && self.ymin <= point[1]
+            && self.zmin <= point[2]
+            && self.xmax >= point[0]
+            && self.ymax >= point[1]
+            && self.zmax >= point[2];
+    }
+
+    /**
+    Returns true if `self` contains a given point, i.e. if the point is within
+    the boundaries of `self`.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.contains_point([0.5, 0.5, 0.5]));
+    assert!(!bb.contains_point([0.0, 0.0, 1.0])); // On boundary
+    ```
+     */
+    pub fn contains_point<P: Into<[T; 3]>>(&self, point: P) -> bool {
+        let point: [T; 3] = point.into();
+        return self.xmin < point[0]
+            && self.ymin < point[1]
+            && self.zmin < point[2]
+            && self.xmax > point[0]
+            && self.ymax > point[1]
+            && self.zmax > point[2];
+    }
+
+    /**
+    Returns true if `self` covers `other`, i.e. if every point covered by
+    `other` is also covered by `self`.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb1 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox3::new(0.2, 1.0, 0.2, 0.8, 0.0, 0.5);
+    assert!(bb1.covers(&bb2));
+    assert!(!bb2.covers(&bb1));
+    ```
+    */
+    pub fn covers(&self, other: &Self) -> bool {
+        return self.xmin <= other.xmin
+            && self.ymin <= other.ymin
+            && self.zmin <= other.zmin
+            && self.xmax >= other.xmax
+            && self.ymax >= other.ymax
+            && self.zmax >= other.zmax;
+    }
+
+    /**
+    Returns true if `self` contains `other`, i.e. if every point covered by
+    `other` is contained within `self`.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb1 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox3::new(0.2, 0.8, 0.2, 0.8, 0.2, 0.8);
+    assert!(bb1.contains(&bb2));
+    assert!(!bb1.contains(&bb1));
+    ```
+    */
+    pub fn contains(&self, other: &Self) -> bool {
+        return self.xmin < other.xmin
+            && self.ymin < other.ymin
+            && self.zmin < other.zmin
+            && self.xmax > other.xmax
+            && self.ymax > other.ymax
+            && self.zmax > other.zmax;
+    }
+
+    /**
+    Returns true if the bounding boxes intersect, i.e. if they both
+    [cover](BoundingBox3::covers) at least one common point.
+
+    The boxes are intersecting if they are just
+    [touching](BoundingBox3::touches).
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb1 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox3::new(0.5, 2.0, 0.5, 2.0, 0.5, 2.0);
+    assert!(bb1.intersects(&bb2));
+
+    // bb1 touches bb3 => intersection
+    let bb3 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 1.0, 2.0);
+    assert!(bb1.intersects(&bb3));
+
+    let bb4 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 1.5, 2.0);
+    assert!(!bb1.intersects(&bb4));
+    ```
+     */
+    pub fn intersects(&self, other: &Self) -> bool {
+        return self.xmin <= other.xmax
+            && other.xmin <= self.xmax
+            && self.ymin <= other.ymax
+            && other.ymin <= self.ymax
+            && self.zmin <= other.zmax
+            && other.zmin <= self.zmax;
+    }
+
+    /**
+    Returns true if the bounding boxes overlap, i.e. if they both
+    [contain](BoundingBox3::contains) at least one common point.
+
+    The boxes are NOT overlapping if they are just
+    [touching](BoundingBox3::touches).
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb1 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox3::new(0.5, 2.0, 0.5, 2.0, 0.5, 2.0);
+    assert!(bb1.overlaps(&bb2));
+
+    // bb1 touches bb3 => no overlapping
+    let bb3 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 1.0, 2.0);
+    assert!(!bb1.overlaps(&bb3));
+    ```
+     */
+    pub fn overlaps(&self, other: &Self) -> bool {
+        return self.xmin < other.xmax
+            && other.xmin < self.xmax
+            && self.ymin < other.ymax
+            && other.ymin < self.ymax
+            && self.zmin < other.zmax
+            && other.zmin < self.zmax;
+    }
+
+    /**
+    Check if the bounding boxes are touching.
+
+    The bounding boxes are touching if they share at least one extremum and are
+    not overlapping each other.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb1 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox3::new(1.0, 2.0, 0.0, 1.0, 0.0, 1.0);
+    assert!(bb1.touches(&bb2));
+
+    let bb3 = BoundingBox3::new(0.0, 2.0, 0.0, 1.0, 0.0, 1.0);
+    assert!(!bb1.touches(&bb3));
+    ```
+     */
+    pub fn touches(&self, other: &Self) -> bool {
+        if self.overlaps(other) || self.has_nan() || other.has_nan() {
+            return false;
+        } else {
+            return self.xmin == other.xmax
+                || self.xmax == other.xmin
+                || self.ymin == other.ymax
+                || self.ymax == other.ymin
+                || self.zmin == other.zmax
+                || self.zmax == other.zmin;
+        }
+    }
+
+    /**
+    Returns the width (extent in x-direction) of the bounding box.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb = BoundingBox3::new(-1.0, 1.0, 2.0, 7.0, 0.0, 3.0);
+    assert_eq!(bb.width(), 2.0);
+    ```
+     */
+    pub fn width(&self) -> T {
+        return self.xmax - self.xmin;
+    }
+
+    /**
+    Returns the height (extent in y-direction) of the bounding box.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb = BoundingBox3::new(-1.0, 1.0, 2.0, 7.0, 0.0, 3.0);
+    assert_eq!(bb.height(), 5.0);
+    ```
+     */
+    pub fn height(&self) -> T {
+        return self.ymax - self.ymin;
+    }
+
+    /**
+    Returns the depth (extent in z-direction) of the bounding box.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb = BoundingBox3::new(-1.0, 1.0, 2.0, 7.0, 0.0, 3.0);
+    assert_eq!(bb.depth(), 3.0);
+    ```
+     */
+    pub fn depth(&self) -> T {
+        return self.zmax - self.zmin;
+    }
+
+    /**
+    Returns the volume of the bounding box.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb = BoundingBox3::new(-1.0, 1.0, 2.0, 7.0, 0.0, 3.0);
+    assert_eq!(bb.volume(), 30.0);
+    ```
+     */
+    pub fn volume(&self) -> T {
+        return self.width() * self.height() * self.depth();
+    }
+
+    /**
+    Returns the center of the bounding box.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb = BoundingBox3::new(-1.0, 1.0, 2.0, 7.0, 0.0, 3.0);
+    assert_eq!(bb.center(), [0.0, 4.5, 1.5]);
+    ```
+     */
+    pub fn center(&self) -> [T; 3] {
+        let x = self.xmin.midpoint(self.xmax);
+        let y = self.ymin.midpoint(self.ymax);
+        let z = self.zmin.midpoint(self.zmax);
+        return [x, y, z];
+    }
+
+    /**
+    Translates the bounding box by the given `shift`.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let mut bb = BoundingBox3::new(0.0, 1.0, 1.0, 2.0, -1.0, 0.0);
+    bb.translate([1.0, -1.0, 1.0]);
+    assert_eq!(bb, BoundingBox3::new(1.0, 2.0, 0.0, 1.0, 0.0, 1.0));
+    ```
+     */
+    pub fn translate<P: Into<[T; 3]>>(&mut self, shift: P) {
+        let shift: [T; 3] = shift.into();
+        self.xmin += shift[0];
+        self.xmax += shift[0];
+        self.ymin += shift[1];
+        self.ymax += shift[1];
+        self.zmin += shift[2];
+        self.zmax += shift[2];
+    }
+
+    /**
+    Scales the width, height and depth of `self` while keeping the center fixed.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let mut bb = BoundingBox3::new(0.0, 1.0, 2.0, 4.0, 0.0, 4.0);
+    bb.scale(2.0);
+
+    assert_eq!(bb.center(), [0.5, 3.0, 2.0]);
+    assert_eq!(bb, BoundingBox3::new(-0.5, 1.5, 1.0, 5.0, -2.0, 6.0));
+    ```
+     */
+    pub fn scale(&mut self, factor: T) {
+        let two = T::ONE + T::ONE;
+        let dw = (factor - T::ONE) * self.width() / two;
+        let dh = (factor - T::ONE) * self.height() / two;
+        let dd = (factor - T::ONE) * self.depth() / two;
+        self.xmin -= dw;
+        self.xmax += dw;
+        self.ymin -= dh;
+        self.ymax += dh;
+        self.zmin -= dd;
+        self.zmax += dd;
+    }
+
+    /**
+    Remove any singular dimensions by "buffering" them with `add_to_extr`.
+
+    See [`BoundingBox::remove_singular_dimensions`](crate::BoundingBox::remove_singular_dimensions).
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let mut bb = BoundingBox3::new(0.0, 0.0, -1.0, 1.0, 2.0, 2.0);
+    bb.remove_singular_dimensions(1.0);
+    assert_eq!(bb, BoundingBox3::new(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0));
+    ```
+     */
+    pub fn remove_singular_dimensions(&mut self, add_to_extr: T) {
+        if self.width() == T::ZERO {
+            self.xmin -= add_to_extr;
+            self.xmax += add_to_extr;
+        }
+        if self.height() == T::ZERO {
+            self.ymin -= add_to_extr;
+            self.ymax += add_to_extr;
+        }
+        if self.depth() == T::ZERO {
+            self.zmin -= add_to_extr;
+            self.zmax += add_to_extr;
+        }
+    }
+
+    /**
+    Returns true if the bounding box is finite.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    assert!(BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0).is_finite());
+    assert!(!BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, f64::INFINITY).is_finite());
+    ```
+     */
+    pub fn is_finite(&self) -> bool {
+        return self.xmin.is_finite()
+            && self.xmax.is_finite()
+            && self.ymin.is_finite()
+            && self.ymax.is_finite()
+            && self.zmin.is_finite()
+            && self.zmax.is_finite();
+    }
+
+    /**
+    Losslessly converts the bounding box into a bounding box with a different
+    scalar type `U`. See [`BoundingBox::convert`](crate::BoundingBox::convert).
+     */
+    pub fn convert<U: Scalar + From<T>>(self) -> BoundingBox3<U> {
+        return BoundingBox3 {
+            xmin: self.xmin.into(),
+            xmax: self.xmax.into(),
+            ymin: self.ymin.into(),
+            ymax: self.ymax.into(),
+            zmin: self.zmin.into(),
+            zmax: self.zmax.into(),
+        };
+    }
+
+    /**
+    Converts the bounding box into a bounding box with a different scalar type
+    `U`. This conversion may be lossy. See
+    [`BoundingBox::cast`](crate::BoundingBox::cast).
+     */
+    pub fn cast<U: Scalar>(self) -> BoundingBox3<U> {
+        return BoundingBox3 {
+            xmin: U::from_f64(self.xmin.to_f64()),
+            xmax: U::from_f64(self.xmax.to_f64()),
+            ymin: U::from_f64(self.ymin.to_f64()),
+            ymax: U::from_f64(self.ymax.to_f64()),
+            zmin: U::from_f64(self.zmin.to_f64()),
+            zmax: U::from_f64(self.zmax.to_f64()),
+        };
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T: Scalar + UlpsEq<Epsilon = T>> BoundingBox3<T> {
+    /**
+    Like [`BoundingBox3::covers_point`], but with absolute and ULPs tolerances.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    assert!(!bb.covers_point([1.0, 1.0, 1.0001]));
+    assert!(bb.approx_covers_point([1.0, 1.0, 1.0001], 1e-3, 0));
+    ```
+
+    # Features
+
+    This function uses the [`ulps_eq`] macro of the [approx] crate, therefore
+    the `approx` feature needs to be enabled.
+     */
+    pub fn approx_covers_point<P: Into<[T; 3]>>(
+        &self,
+        point: P,
+        epsilon: T,
+        max_ulps: u32,
+    ) -> bool {
+        let point: [T; 3] = point.into();
+        let le = |a: T, b: T| a < b || ulps_eq!(a, b, epsilon = epsilon, max_ulps = max_ulps);
+        return le(self.xmin, point[0])
+            && le(self.ymin, point[1])
+            && le(self.zmin, point[2])
+            && le(point[0], self.xmax)
+            && le(point[1], self.ymax)
+            && le(point[2], self.zmax);
+    }
+
+    /**
+    Like [`BoundingBox3::covers`], but with absolute and ULPs tolerances.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb1 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox3::new(0.0, 1.0001, 0.0, 0.5, 0.0, 0.5);
+    assert!(!bb1.covers(&bb2));
+    assert!(bb1.approx_covers(&bb2, 1e-3, 0));
+    ```
+
+    # Features
+
+    This function uses the [`ulps_eq`] macro of the [approx] crate, therefore
+    the `approx` feature needs to be enabled.
+     */
+    pub fn approx_covers(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        let le = |a: T, b: T| a < b || ulps_eq!(a, b, epsilon = epsilon, max_ulps = max_ulps);
+        return le(self.xmin, other.xmin)
+            && le(self.ymin, other.ymin)
+            && le(self.zmin, other.zmin)
+            && le(other.xmax, self.xmax)
+            && le(other.ymax, self.ymax)
+            && le(other.zmax, self.zmax);
+    }
+
+    /**
+    Check if the two bounding boxes are approximately equal.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb1 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0001);
+    assert!(bb1.approx_eq(&bb2, 1e-3, 0));
+    assert!(!bb1.approx_eq(&bb2, 1e-6, 0));
+    ```
+
+    # Features
+
+    This function uses the [`ulps_eq`] macro of the [approx] crate, therefore
+    the `approx` feature needs to be enabled.
+     */
+    pub fn approx_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        let eq = |a: T, b: T| ulps_eq!(a, b, epsilon = epsilon, max_ulps = max_ulps);
+        return eq(self.xmin, other.xmin)
+            && eq(self.xmax, other.xmax)
+            && eq(self.ymin, other.ymin)
+            && eq(self.ymax, other.ymax)
+            && eq(self.zmin, other.zmin)
+            && eq(self.zmax, other.zmax);
+    }
+
+    /**
+    Like [`BoundingBox3::touches`], but with absolute and ULPs tolerances.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox3;
+
+    let bb1 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 1.0001, 2.0);
+    assert!(!bb1.touches(&bb2));
+    assert!(bb1.approx_touches(&bb2, 1e-3, 0));
+    ```
+
+    # Features
+
+    This function uses the [`ulps_eq`] macro of the [approx] crate, therefore
+    the `approx` feature needs to be enabled.
+     */
+    pub fn approx_touches(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        if self.overlaps(other) || self.has_nan() || other.has_nan() {
+            return false;
+        }
+        let eq = |a: T, b: T| ulps_eq!(a, b, epsilon = epsilon, max_ulps = max_ulps);
+        return eq(self.xmin, other.xmax)
+            || eq(self.xmax, other.xmin)
+            || eq(self.ymin, other.ymax)
+            || eq(self.ymax, other.ymin)
+            || eq(self.zmin, other.zmax)
+            || eq(self.zmax, other.zmin);
+    }
+}
+
+macro_rules! impl_from_point3 {
+    ($($t:ty),*) => {
+        $(
+            impl From<[$t; 3]> for BoundingBox3<$t> {
+                fn from(v: [$t; 3]) -> Self {
+                    return (&v).into();
+                }
+            }
+
+            impl From<&'_ [$t; 3]> for BoundingBox3<$t> {
+                fn from(v: &'_ [$t; 3]) -> Self {
+                    return BoundingBox3::new(v[0], v[0], v[1], v[1], v[2], v[2]);
+                }
+            }
+        )*
+    };
+}
+
+impl_from_point3!(f32, f64, i8, i16, i32, i64, i128, isize);
+
+/**
+This trait provides a standardized way of deriving a [`BoundingBox3`] from
+another type `T` with the [`bounding_box3`](ToBoundingBox3::bounding_box3)
+method. It is the 3-dimensional counterpart of
+[`ToBoundingBox`](crate::ToBoundingBox).
+
+Implementing [`ToBoundingBox3`] also auto-implements a [`From<&T>`]
+implementation for [`BoundingBox3`].
+
+```
+use bounding_box::{BoundingBox3, ToBoundingBox3};
+
+struct Sphere {
+    center: [f64; 3],
+    radius: f64
+}
+
+impl ToBoundingBox3 for Sphere {
+    fn bounding_box3(&self) -> BoundingBox3 {
+        return BoundingBox3::new(self.center[0] - self.radius,
+                                 self.center[0] + self.radius,
+                                 self.center[1] - self.radius,
+                                 self.center[1] + self.radius,
+                                 self.center[2] - self.radius,
+                                 self.center[2] + self.radius);
+    }
+}
+
+let s = Sphere {center: [0.0, 0.0, 0.0], radius: 1.0};
+assert_eq!(s.bounding_box3(), BoundingBox3::from(&s));
+```
+ */
+pub trait ToBoundingBox3<T: Scalar = f64> {
+    /**
+    Returns a 3-dimensional bounding box for the implementor.
+     */
+    fn bounding_box3(&self) -> BoundingBox3<T>;
+}
+
+impl<T: Scalar> ToBoundingBox3<T> for BoundingBox3<T> {
+    fn bounding_box3(&self) -> BoundingBox3<T> {
+        return *self;
+    }
+}
+
+impl<T: Scalar, E: ToBoundingBox3<T>> From<&E> for BoundingBox3<T> {
+    fn from(value: &E) -> Self {
+        value.bounding_box3()
+    }
+}
@@ -0,0 +1,631 @@
+/*!
+Defines the [`Bvh`] (bounding volume hierarchy) for static and moving items
+implementing [`ToBoundingBox`](crate::ToBoundingBox).
+ */
+
+use std::cmp::Ordering;
+
+use crate::{BoundingBox, Scalar, ToBoundingBox};
+
+/**
+Handle of an item stored in a [`Bvh`].
+
+A key is returned by [`Bvh::insert`] (or is the index of the item for
+[`Bvh::build`]) and stays valid until the item is [removed](Bvh::remove).
+Afterwards, the key may be reused for another item.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BvhKey(usize);
+
+/**
+Strategy used by [`Bvh::build`] to split a set of items into two subtrees.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BvhSplit {
+    /// Split the items at the median of their bounding box centers along the
+    /// axis with the largest extent. This is fast to build and results in a
+    /// perfectly balanced tree.
+    Median,
+    /// Choose the split which minimizes the surface area heuristic (SAH), i.e.
+    /// the sum of the areas of both subtrees weighted by their number of items.
+    /// This takes longer to build, but usually results in faster queries.
+    #[default]
+    Sah,
+}
+
+/**
+A [bounding volume hierarchy](https://en.wikipedia.org/wiki/Bounding_volume_hierarchy)
+over items implementing [`ToBoundingBox`].
+
+A BVH is a binary tree whose leaves contain one item each and whose internal
+nodes store the [union](BoundingBox::union) of the bounding boxes of their
+children. In contrast to the [`RTree`](crate::RTree), the tree topology is kept
+when items move: after the items have been changed (e.g. translated), a
+[`refit`](Bvh::refit) recomputes all bounding boxes bottom-up without
+rebuilding the tree. This makes the BVH well suited for animated scenes where
+all items move a little each frame. If only a few items moved,
+[`update`](Bvh::update) refits only the ancestors of a single item.
+
+The tree can be built top-down from a set of items with [`Bvh::build`] and
+modified incrementally with [`insert`](Bvh::insert) and
+[`remove`](Bvh::remove). Since refitting does not change the topology, the
+query performance degrades if items move far from their original position; in
+this case, the tree should be rebuilt.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, Bvh, BvhSplit};
+
+let boxes: Vec<_> = (0..10).map(|i| {
+    let x = i as f64;
+    BoundingBox::new(x, x + 0.5, 0.0, 1.0)
+}).collect();
+let mut bvh = Bvh::build(boxes, BvhSplit::Sah);
+
+let query = BoundingBox::new(2.2, 4.2, 0.0, 1.0);
+assert_eq!(bvh.query_intersecting(&query).len(), 3);
+
+// Move all boxes up and refit the hierarchy
+for (_, bb) in bvh.iter_mut() {
+    bb.translate([0.0, 5.0]);
+}
+bvh.refit();
+assert_eq!(bvh.query_intersecting(&query).len(), 0);
+assert_eq!(bvh.query_point([4.2, 5.5]).len(), 1);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct Bvh<T, S = f64> {
+    nodes: Vec<Node<S>>,
+    free_nodes: Vec<usize>,
+    root: Option<usize>,
+    slots: Vec<Option<Slot<T>>>,
+    free_slots: Vec<usize>,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Slot<T> {
+    item: T,
+    leaf: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Node<S> {
+    bb: BoundingBox<S>,
+    parent: Option<usize>,
+    kind: NodeKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NodeKind {
+    Leaf(usize),
+    Internal(usize, usize),
+}
+
+impl<T: ToBoundingBox<S>, S: Scalar> Bvh<T, S> {
+    /**
+    Creates an empty hierarchy.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Bvh};
+
+    let bvh: Bvh<BoundingBox> = Bvh::new();
+    assert!(bvh.is_empty());
+    ```
+     */
+    pub fn new() -> Self {
+        return Bvh {
+            nodes: Vec::new(),
+            free_nodes: Vec::new(),
+            root: None,
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+            len: 0,
+        };
+    }
+
+    /**
+    Builds a hierarchy from the given items top-down using the given split
+    strategy. The key of each item is its index in `items`.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Bvh, BvhSplit};
+
+    let boxes = vec![
+        BoundingBox::new(0.0, 1.0, 0.0, 1.0),
+        BoundingBox::new(5.0, 6.0, 0.0, 1.0),
+    ];
+    let bvh = Bvh::build(boxes, BvhSplit::Median);
+    assert_eq!(bvh.len(), 2);
+    assert_eq!(bvh.query_point([5.5, 0.5])[0].1, &BoundingBox::new(5.0, 6.0, 0.0, 1.0));
+    ```
+     */
+    pub fn build(items: Vec<T>, split: BvhSplit) -> Self {
+        let mut bvh = Bvh::new();
+        let mut leaves = Vec::with_capacity(items.len());
+        for (id, item) in items.into_iter().enumerate() {
+            let bb = item.bounding_box();
+            let leaf = bvh.alloc_node(bb, NodeKind::Leaf(id));
+            bvh.slots.push(Some(Slot { item, leaf }));
+            leaves.push(leaf);
+        }
+        bvh.len = leaves.len();
+        if !leaves.is_empty() {
+            bvh.root = Some(bvh.build_subtree(&mut leaves, split));
+        }
+        return bvh;
+    }
+
+    fn build_subtree(&mut self, leaves: &mut [usize], split: BvhSplit) -> usize {
+        if leaves.len() == 1 {
+            return leaves[0];
+        }
+        let mid = match split {
+            BvhSplit::Median => self.median_split(leaves),
+            BvhSplit::Sah => self.sah_split(leaves),
+        };
+        let (left, right) = leaves.split_at_mut(mid);
+        let left = self.build_subtree(left, split);
+        let right = self.build_subtree(right, split);
+        let bb = self.nodes[left].bb.union(&self.nodes[right].bb);
+        let node = self.alloc_node(bb, NodeKind::Internal(left, right));
+        self.nodes[left].parent = Some(node);
+        self.nodes[right].parent = Some(node);
+        return node;
+    }
+
+    fn sort_by_center(&self, leaves: &mut [usize], axis: usize) {
+        leaves.sort_by(|a, b| {
+            let ca = self.nodes[*a].bb.center()[axis];
+            let cb = self.nodes[*b].bb.center()[axis];
+            ca.partial_cmp(&cb).unwrap_or(Ordering::Equal)
+        });
+    }
+
+    /// Sorts the leaves along the axis with the largest extent of the centers
+    /// and returns the index of the median.
+    fn median_split(&self, leaves: &mut [usize]) -> usize {
+        let centers = BoundingBox::from_points(leaves.iter().map(|l| self.nodes[*l].bb.center()))
+            .expect("at least two leaves");
+        let axis = if centers.width() >= centers.height() {
+            0
+        } else {
+            1
+        };
+        self.sort_by_center(leaves, axis);
+        return leaves.len() / 2;
+    }
+
+    /// Sorts the leaves along the axis with the cheapest split according to
+    /// the surface area heuristic and returns the index of the split.
+    fn sah_split(&self, leaves: &mut [usize]) -> usize {
+        let area = |bb: &BoundingBox<S>| (bb.width() * bb.height()).to_f64();
+        let mut best = (f64::INFINITY, 0, leaves.len() / 2);
+        for axis in 0..2 {
+            self.sort_by_center(leaves, axis);
+            // Costs of all left parts, then evaluate together with the right parts
+            let mut left_costs = Vec::with_capacity(leaves.len());
+            let mut acc = self.nodes[leaves[0]].bb;
+            for (count, leaf) in leaves.iter().enumerate() {
+                acc = acc.union(&self.nodes[*leaf].bb);
+                left_costs.push(area(&acc) * (count + 1) as f64);
+            }
+            let mut acc = self.nodes[leaves[leaves.len() - 1]].bb;
+            for mid in (1..leaves.len()).rev() {
+                acc = acc.union(&self.nodes[leaves[mid]].bb);
+                let cost = left_costs[mid - 1] + area(&acc) * (leaves.len() - mid) as f64;
+                if cost < best.0 {
+                    best = (cost, axis, mid);
+                }
+            }
+        }
+        self.sort_by_center(leaves, best.1);
+        return best.2;
+    }
+
+    fn alloc_node(&mut self, bb: BoundingBox<S>, kind: NodeKind) -> usize {
+        let node = Node {
+            bb,
+            parent: None,
+            kind,
+        };
+        match self.free_nodes.pop() {
+            Some(idx) => {
+                self.nodes[idx] = node;
+                return idx;
+            }
+            None => {
+                self.nodes.push(node);
+                return self.nodes.len() - 1;
+            }
+        }
+    }
+
+    /**
+    Returns the number of items stored in the hierarchy.
+     */
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    /**
+    Returns true if the hierarchy does not contain any items.
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /**
+    Returns the bounding box of all items or `None` if the hierarchy is empty.
+     */
+    pub fn bounding_box(&self) -> Option<BoundingBox<S>> {
+        return self.root.map(|root| self.nodes[root].bb);
+    }
+
+    /**
+    Returns a reference to the item with the given key.
+     */
+    pub fn get(&self, key: BvhKey) -> Option<&T> {
+        return self.slots.get(key.0)?.as_ref().map(|slot| &slot.item);
+    }
+
+    /**
+    Returns a mutable reference to the item with the given key.
+
+    If the bounding box of the item is changed via this reference,
+    [`Bvh::update`] or [`Bvh::refit`] must be called afterwards.
+     */
+    pub fn get_mut(&mut self, key: BvhKey) -> Option<&mut T> {
+        return self
+            .slots
+            .get_mut(key.0)?
+            .as_mut()
+            .map(|slot| &mut slot.item);
+    }
+
+    /**
+    Returns an iterator over all keys and items.
+     */
+    pub fn iter(&self) -> impl Iterator<Item = (BvhKey, &T)> {
+        return self
+            .slots
+            .iter()
+            .enumerate()
+            .filter_map(|(id, slot)| slot.as_ref().map(|slot| (BvhKey(id), &slot.item)));
+    }
+
+    /**
+    Returns an iterator over all keys and mutable references to the items.
+
+    If the bounding boxes of the items are changed via these references,
+    [`Bvh::refit`] must be called afterwards.
+     */
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (BvhKey, &mut T)> {
+        return self
+            .slots
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(id, slot)| slot.as_mut().map(|slot| (BvhKey(id), &mut slot.item)));
+    }
+
+    /**
+    Inserts an item into the hierarchy and returns its key.
+
+    The new leaf is paired with the sibling which results in the smallest
+    increase of the total area of the tree.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Bvh};
+
+    let mut bvh = Bvh::new();
+    let key = bvh.insert(BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    bvh.insert(BoundingBox::new(2.0, 3.0, 0.0, 1.0));
+    assert_eq!(bvh.query_point([0.5, 0.5]), vec![(key, &BoundingBox::new(0.0, 1.0, 0.0, 1.0))]);
+    ```
+     */
+    pub fn insert(&mut self, item: T) -> BvhKey {
+        let bb = item.bounding_box();
+        let id = match self.free_slots.pop() {
+            Some(id) => id,
+            None => {
+                self.slots.push(None);
+                self.slots.len() - 1
+            }
+        };
+        let leaf = self.alloc_node(bb, NodeKind::Leaf(id));
+        self.slots[id] = Some(Slot { item, leaf });
+        self.len += 1;
+        self.insert_leaf(leaf);
+        return BvhKey(id);
+    }
+
+    fn insert_leaf(&mut self, leaf: usize) {
+        let mut sibling = match self.root {
+            Some(root) => root,
+            None => {
+                self.root = Some(leaf);
+                return;
+            }
+        };
+        let bb = self.nodes[leaf].bb;
+        let area = |bb: &BoundingBox<S>| (bb.width() * bb.height()).to_f64();
+
+        // Descend towards the child whose area grows the least
+        while let NodeKind::Internal(left, right) = self.nodes[sibling].kind {
+            let union_area = area(&self.nodes[sibling].bb.union(&bb));
+            let cost_here = union_area;
+            let growth = |child: usize| {
+                let child_bb = &self.nodes[child].bb;
+                area(&child_bb.union(&bb)) - area(child_bb) + union_area
+                    - area(&self.nodes[sibling].bb)
+            };
+            let (cost_left, cost_right) = (growth(left), growth(right));
+            if cost_here <= cost_left && cost_here <= cost_right {
+                break;
+            }
+            sibling = if cost_left <= cost_right { left } else { right };
+        }
+
+        let old_parent = self.nodes[sibling].parent;
+        let union = self.nodes[sibling].bb.union(&bb);
+        let parent = self.alloc_node(union, NodeKind::Internal(sibling, leaf));
+        self.nodes[parent].parent = old_parent;
+        self.nodes[sibling].parent = Some(parent);
+        self.nodes[leaf].parent = Some(parent);
+        match old_parent {
+            Some(grand_parent) => {
+                self.replace_child(grand_parent, sibling, parent);
+                self.refit_ancestors(grand_parent);
+            }
+            None => self.root = Some(parent),
+        }
+    }
+
+    fn replace_child(&mut self, node: usize, old: usize, new: usize) {
+        if let NodeKind::Internal(left, right) = self.nodes[node].kind {
+            self.nodes[node].kind = if left == old {
+                NodeKind::Internal(new, right)
+            } else {
+                NodeKind::Internal(left, new)
+            };
+        }
+    }
+
+    /// Recomputes the bounding boxes of `node` and all its ancestors from
+    /// their children.
+    fn refit_ancestors(&mut self, node: usize) {
+        let mut current = Some(node);
+        while let Some(idx) = current {
+            if let NodeKind::Internal(left, right) = self.nodes[idx].kind {
+                self.nodes[idx].bb = self.nodes[left].bb.union(&self.nodes[right].bb);
+            }
+            current = self.nodes[idx].parent;
+        }
+    }
+
+    /**
+    Removes the item with the given key from the hierarchy and returns it. If
+    the key is not valid, `None` is returned.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Bvh};
+
+    let mut bvh = Bvh::new();
+    let key = bvh.insert(BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    bvh.insert(BoundingBox::new(2.0, 3.0, 0.0, 1.0));
+
+    assert!(bvh.remove(key).is_some());
+    assert!(bvh.remove(key).is_none());
+    assert_eq!(bvh.bounding_box(), Some(BoundingBox::new(2.0, 3.0, 0.0, 1.0)));
+    ```
+     */
+    pub fn remove(&mut self, key: BvhKey) -> Option<T> {
+        let slot = self.slots.get_mut(key.0)?.take()?;
+        self.free_slots.push(key.0);
+        self.len -= 1;
+        self.remove_leaf(slot.leaf);
+        self.free_nodes.push(slot.leaf);
+        return Some(slot.item);
+    }
+
+    fn remove_leaf(&mut self, leaf: usize) {
+        let parent = match self.nodes[leaf].parent {
+            Some(parent) => parent,
+            None => {
+                self.root = None;
+                return;
+            }
+        };
+        let sibling = match self.nodes[parent].kind {
+            NodeKind::Internal(left, right) if left == leaf => right,
+            NodeKind::Internal(left, _) => left,
+            NodeKind::Leaf(_) => unreachable!("parent nodes are internal"),
+        };
+
+        // Replace the parent by the sibling
+        let grand_parent = self.nodes[parent].parent;
+        self.nodes[sibling].parent = grand_parent;
+        self.free_nodes.push(parent);
+        match grand_parent {
+            Some(grand_parent) => {
+                self.replace_child(grand_parent, parent, sibling);
+                self.refit_ancestors(grand_parent);
+            }
+            None => self.root = Some(sibling),
+        }
+    }
+
+    /**
+    Recalculates the bounding box of the item with the given key and refits
+    all its ancestors. Returns false if the key is not valid.
+
+    This is cheaper than [`Bvh::refit`] if only a few items have changed.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Bvh, BvhSplit};
+
+    let mut bvh = Bvh::build(vec![
+        BoundingBox::new(0.0, 1.0, 0.0, 1.0),
+        BoundingBox::new(2.0, 3.0, 0.0, 1.0),
+    ], BvhSplit::Sah);
+    let key = bvh.query_point([0.5, 0.5])[0].0;
+
+    bvh.get_mut(key).unwrap().translate([10.0, 0.0]);
+    assert!(bvh.update(key));
+    assert_eq!(bvh.bounding_box(), Some(BoundingBox::new(2.0, 11.0, 0.0, 1.0)));
+    ```
+     */
+    pub fn update(&mut self, key: BvhKey) -> bool {
+        let (leaf, bb) = match self.slots.get(key.0) {
+            Some(Some(slot)) => (slot.leaf, slot.item.bounding_box()),
+            _ => return false,
+        };
+        self.nodes[leaf].bb = bb;
+        if let Some(parent) = self.nodes[leaf].parent {
+            self.refit_ancestors(parent);
+        }
+        return true;
+    }
+
+    /**
+    Recalculates the bounding boxes of all items and recomputes the bounding
+    boxes of all internal nodes bottom-up via [`BoundingBox::union`].
+
+    The topology of the tree is not changed.
+     */
+    pub fn refit(&mut self) {
+        for slot in self.slots.iter().flatten() {
+            self.nodes[slot.leaf].bb = slot.item.bounding_box();
+        }
+        if let Some(root) = self.root {
+            // Iterative post-order traversal
+            let mut stack = vec![(root, false)];
+            while let Some((node, children_done)) = stack.pop() {
+                if let NodeKind::Internal(left, right) = self.nodes[node].kind {
+                    if children_done {
+                        self.nodes[node].bb = self.nodes[left].bb.union(&self.nodes[right].bb);
+                    } else {
+                        stack.push((node, true));
+                        stack.push((left, false));
+                        stack.push((right, false));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Visits all leaves whose ancestors all fulfill `node_pred` and returns
+    /// those which fulfill `node_pred` themselves.
+    fn select(&self, node_pred: impl Fn(&BoundingBox<S>) -> bool) -> Vec<(BvhKey, &T)> {
+        let mut out = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if !node_pred(&node.bb) {
+                continue;
+            }
+            match node.kind {
+                NodeKind::Leaf(id) => {
+                    let slot = self.slots[id]
+                        .as_ref()
+                        .expect("leaves reference valid slots");
+                    out.push((BvhKey(id), &slot.item));
+                }
+                NodeKind::Internal(left, right) => {
+                    stack.push(right);
+                    stack.push(left);
+                }
+            }
+        }
+        return out;
+    }
+
+    /**
+    Returns the keys and items whose bounding box
+    [intersects](BoundingBox::intersects) `query`.
+     */
+    pub fn query_intersecting(&self, query: &BoundingBox<S>) -> Vec<(BvhKey, &T)> {
+        return self.select(|bb| bb.intersects(query));
+    }
+
+    /**
+    Returns the keys and items whose bounding box
+    [covers](BoundingBox::covers_point) the given point.
+     */
+    pub fn query_point<P: Into<[S; 2]>>(&self, point: P) -> Vec<(BvhKey, &T)> {
+        let point: [S; 2] = point.into();
+        return self.select(|bb| bb.covers_point(point));
+    }
+}
+
+impl<T: ToBoundingBox> Bvh<T, f64> {
+    /**
+    Returns the keys and items whose bounding box is hit by the ray starting at
+    `origin` and pointing in `direction`, sorted by the ray parameter `t` at
+    which the ray enters the respective box.
+
+    Points on the ray are given by `origin + t * direction` with `t >= 0`. The
+    boxes are tested with [`BoundingBox::ray_intersection`], hence the entry
+    parameter is zero if the origin is inside a bounding box. Subtrees whose
+    bounding box is missed by the ray are skipped.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Bvh, BvhSplit};
+
+    let bvh = Bvh::build(vec![
+        BoundingBox::new(4.0, 5.0, -1.0, 1.0),
+        BoundingBox::new(2.0, 3.0, -1.0, 1.0),
+        BoundingBox::new(2.0, 3.0, 5.0, 6.0),
+    ], BvhSplit::Sah);
+
+    let hits = bvh.ray_cast([0.0, 0.0], [1.0, 0.0]);
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].1, &BoundingBox::new(2.0, 3.0, -1.0, 1.0));
+    assert_eq!(hits[0].2, 2.0);
+    assert_eq!(hits[1].2, 4.0);
+    ```
+     */
+    pub fn ray_cast<P: Into<[f64; 2]>, D: Into<[f64; 2]>>(
+        &self,
+        origin: P,
+        direction: D,
+    ) -> Vec<(BvhKey, &T, f64)> {
+        let origin: [f64; 2] = origin.into();
+        let direction: [f64; 2] = direction.into();
+        let mut hits: Vec<(BvhKey, &T, f64)> = self
+            .select(|bb| bb.ray_intersection(origin, direction).is_some())
+            .into_iter()
+            .map(|(key, item)| {
+                let leaf = self.slots[key.0].as_ref().expect("key is valid").leaf;
+                let t = self.nodes[leaf]
+                    .bb
+                    .ray_intersection(origin, direction)
+                    .expect("box is hit")
+                    .0;
+                (key, item, t)
+            })
+            .collect();
+        hits.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(Ordering::Equal));
+        return hits;
+    }
+}
+
+impl<T: ToBoundingBox<S>, S: Scalar> Default for Bvh<T, S> {
+    fn default() -> Self {
+        return Bvh::new();
+    }
+}
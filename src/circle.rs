@@ -0,0 +1,413 @@
+/*!
+Defines the [`Circle`] and its interaction with [`BoundingBox`].
+ */
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{BoundingBox, ToBoundingBox};
+
+/**
+A circle defined by its center and radius.
+
+Circles are often used as bounding volumes themselves, e.g. for rotating
+entities whose [`BoundingBox`] would change with each rotation. The
+[minimum enclosing circle](Circle::min_enclosing) of a point set can be
+computed with Welzl's algorithm, and circles can be tested for intersection
+with and containment of [`BoundingBox`] instances. Via [`ToBoundingBox`], a
+circle can be used wherever a [`BoundingBox`] is expected.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, Circle};
+
+let circle = Circle::new([0.0, 0.0], 1.0);
+assert_eq!(BoundingBox::from(&circle), BoundingBox::new(-1.0, 1.0, -1.0, 1.0));
+
+// The corner of the box is outside the circle
+let bb = BoundingBox::new(0.8, 2.0, 0.8, 2.0);
+assert!(bb.intersects(&BoundingBox::from(&circle)));
+assert!(!circle.intersects_box(&bb));
+```
+
+# Features
+
+This struct can be serialized / deserialized if the `serde` feature is enabled.
+Deserialization validates center and radius like [`try_new`](Circle::try_new)
+does.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "CircleData"))]
+pub struct Circle {
+    center: [f64; 2],
+    radius: f64,
+}
+
+/// Unvalidated serde representation of a [`Circle`].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "Circle")]
+struct CircleData {
+    center: [f64; 2],
+    radius: f64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CircleData> for Circle {
+    type Error = &'static str;
+
+    fn try_from(data: CircleData) -> Result<Self, Self::Error> {
+        return Circle::try_new(data.center, data.radius)
+            .ok_or("invalid circle: radius must not be negative or NaN and center must be finite");
+    }
+}
+
+impl Circle {
+    /**
+    Generates a circle from its center and radius.
+
+    # Panics
+    Panics if `radius` is negative or NaN or if `center` is not finite.
+
+    # Examples
+
+    ```
+    use bounding_box::Circle;
+
+    let circle = Circle::new([1.0, 2.0], 3.0);
+    assert_eq!(circle.center(), [1.0, 2.0]);
+    assert_eq!(circle.radius(), 3.0);
+    ```
+
+    This example panics because the radius is negative:
+
+    ```should_panic
+    use bounding_box::Circle;
+
+    let _ = Circle::new([1.0, 2.0], -3.0);
+    ```
+     */
+    pub fn new(center: [f64; 2], radius: f64) -> Self {
+        return Self::try_new(center, radius)
+            .expect("radius must not be negative or NaN and center must be finite");
+    }
+
+    /**
+    Like [`Circle::new`], but returns `None` instead of panicking if `radius`
+    is negative or NaN or if `center` is not finite. An infinite radius is
+    allowed.
+
+    # Examples
+
+    ```
+    use bounding_box::Circle;
+
+    assert!(Circle::try_new([0.0, 0.0], 1.0).is_some());
+    assert!(Circle::try_new([0.0, 0.0], f64::INFINITY).is_some());
+    assert!(Circle::try_new([0.0, 0.0], -1.0).is_none());
+    assert!(Circle::try_new([f64::NAN, 0.0], 1.0).is_none());
+    assert!(Circle::try_new([f64::INFINITY, 0.0], 1.0).is_none());
+    ```
+     */
+    pub fn try_new(center: [f64; 2], radius: f64) -> Option<Self> {
+        if radius.is_nan() || radius < 0.0 || !center[0].is_finite() || !center[1].is_finite() {
+            return None;
+        }
+        return Some(Circle { center, radius });
+    }
+
+    /**
+    Returns the center of the circle.
+     */
+    pub fn center(&self) -> [f64; 2] {
+        return self.center;
+    }
+
+    /**
+    Returns the radius of the circle.
+     */
+    pub fn radius(&self) -> f64 {
+        return self.radius;
+    }
+
+    /// Returns the squared distance between the center and `point`.
+    fn squared_distance(&self, point: [f64; 2]) -> f64 {
+        let dx = point[0] - self.center[0];
+        let dy = point[1] - self.center[1];
+        return dx * dx + dy * dy;
+    }
+
+    /**
+    Returns true if `self` covers the given point. Points on the circle line
+    are covered.
+
+    # Examples
+
+    ```
+    use bounding_box::Circle;
+
+    let circle = Circle::new([0.0, 0.0], 5.0);
+    assert!(circle.covers_point([3.0, 4.0]));
+    assert!(!circle.covers_point([3.0, 4.1]));
+    ```
+     */
+    pub fn covers_point<P: Into<[f64; 2]>>(&self, point: P) -> bool {
+        return self.squared_distance(point.into()) <= self.radius * self.radius;
+    }
+
+    /**
+    Returns true if `self` and `other` intersect. Circles which are just
+    touching each other are intersecting.
+
+    # Examples
+
+    ```
+    use bounding_box::Circle;
+
+    let c1 = Circle::new([0.0, 0.0], 1.0);
+    assert!(c1.intersects(&Circle::new([2.0, 0.0], 1.0)));
+    assert!(!c1.intersects(&Circle::new([2.0, 2.0], 1.0)));
+    ```
+     */
+    pub fn intersects(&self, other: &Circle) -> bool {
+        let radii = self.radius + other.radius;
+        return self.squared_distance(other.center) <= radii * radii;
+    }
+
+    /**
+    Returns true if `self` and the bounding box `bb` intersect, i.e. if the
+    point of `bb` closest to the center is covered by the circle. Shapes which
+    are just touching each other are intersecting.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Circle};
+
+    let circle = Circle::new([0.0, 0.0], 1.0);
+    assert!(circle.intersects_box(&BoundingBox::new(1.0, 2.0, -1.0, 1.0)));
+    assert!(!circle.intersects_box(&BoundingBox::new(0.75, 2.0, 0.75, 2.0)));
+    ```
+     */
+    pub fn intersects_box(&self, bb: &BoundingBox<f64>) -> bool {
+        return bb.squared_distance_to_point(self.center) <= self.radius * self.radius;
+    }
+
+    /**
+    Returns true if `self` covers the bounding box `bb` completely, i.e. if all
+    corners of `bb` are covered by the circle.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Circle};
+
+    let circle = Circle::new([0.0, 0.0], 5.0);
+    assert!(circle.covers_box(&BoundingBox::new(-3.0, 3.0, -4.0, 4.0)));
+    assert!(!circle.covers_box(&BoundingBox::new(-3.0, 3.1, -4.0, 4.0)));
+    ```
+     */
+    pub fn covers_box(&self, bb: &BoundingBox<f64>) -> bool {
+        let dx = (bb.xmin() - self.center[0])
+            .abs()
+            .max((bb.xmax() - self.center[0]).abs());
+        let dy = (bb.ymin() - self.center[1])
+            .abs()
+            .max((bb.ymax() - self.center[1]).abs());
+        return dx * dx + dy * dy <= self.radius * self.radius;
+    }
+
+    /**
+    Returns the smallest circle which covers all `points` or `None` if `points`
+    is empty or contains an infinite coordinate. In accordance with the
+    [NaN policy](BoundingBox#nan-policy), points with a NaN coordinate are
+    skipped.
+
+    The circle is computed with
+    [Welzl's algorithm](https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm)
+    in its iterative (move-to-front) form. The points are shuffled with a fixed
+    seed beforehand, which results in an expected linear runtime while keeping
+    the result deterministic. To compensate roundoff errors, points whose
+    distance to the center exceeds the radius by a tiny relative tolerance are
+    still considered covered.
+
+    # Examples
+
+    ```
+    use bounding_box::Circle;
+
+    let points = [[0.0, 0.0], [4.0, 0.0], [2.0, 1.0], [2.0, -1.0]];
+    let circle = Circle::min_enclosing(points.into_iter()).unwrap();
+    assert_eq!(circle.center(), [2.0, 0.0]);
+    assert_eq!(circle.radius(), 2.0);
+
+    let points = [[0.0, 0.0], [f64::NAN, 1.0], [2.0, 0.0]];
+    let circle = Circle::min_enclosing(points.into_iter()).unwrap();
+    assert_eq!(circle.center(), [1.0, 0.0]);
+
+    let points = [[0.0, 0.0], [f64::INFINITY, 1.0]];
+    assert!(Circle::min_enclosing(points.into_iter()).is_none());
+    ```
+     */
+    pub fn min_enclosing<P: Into<[f64; 2]>, I: Iterator<Item = P>>(points: I) -> Option<Self> {
+        let mut points: Vec<[f64; 2]> = points.map(Into::into).collect();
+        points.retain(|point| !point[0].is_nan() && !point[1].is_nan());
+        if points
+            .iter()
+            .any(|point| point[0].is_infinite() || point[1].is_infinite())
+        {
+            return None;
+        }
+
+        // Deterministic Fisher-Yates shuffle (xorshift)
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        for idx in (1..points.len()).rev() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            points.swap(idx, (state % (idx as u64 + 1)) as usize);
+        }
+
+        let covers = |circle: &Circle, point: [f64; 2]| {
+            let tolerance = 1e-12 * (circle.radius + 1.0);
+            return circle.squared_distance(point).sqrt() <= circle.radius + tolerance;
+        };
+
+        let mut circle = Circle::new(*points.first()?, 0.0);
+        for i in 1..points.len() {
+            if covers(&circle, points[i]) {
+                continue;
+            }
+            circle = Circle::new(points[i], 0.0);
+            for j in 0..i {
+                if covers(&circle, points[j]) {
+                    continue;
+                }
+                circle = circle_from_two(points[i], points[j]);
+                for k in 0..j {
+                    if !covers(&circle, points[k]) {
+                        circle = circle_from_three(points[i], points[j], points[k]);
+                    }
+                }
+            }
+        }
+        return Some(circle);
+    }
+}
+
+/// Returns the circle with the segment from `a` to `b` as diameter.
+fn circle_from_two(a: [f64; 2], b: [f64; 2]) -> Circle {
+    // Halving before adding keeps the center finite for finite points
+    let center = [0.5 * a[0] + 0.5 * b[0], 0.5 * a[1] + 0.5 * b[1]];
+    let radius = 0.5 * ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt();
+    return Circle::new(center, radius);
+}
+
+/// Returns the circumcircle of the triangle `a`, `b`, `c`. If the points are
+/// (almost) collinear, i.e. if the circumcircle has no finite center, the
+/// circle spanned by the two most distant points is returned instead.
+fn circle_from_three(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> Circle {
+    let (bx, by) = (b[0] - a[0], b[1] - a[1]);
+    let (cx, cy) = (c[0] - a[0], c[1] - a[1]);
+    let d = 2.0 * (bx * cy - by * cx);
+    if d != 0.0 {
+        let b2 = bx * bx + by * by;
+        let c2 = cx * cx + cy * cy;
+        let ux = (cy * b2 - by * c2) / d;
+        let uy = (bx * c2 - cx * b2) / d;
+        if let Some(circle) = Circle::try_new([a[0] + ux, a[1] + uy], ux.hypot(uy)) {
+            return circle;
+        }
+    }
+    let candidates = [
+        circle_from_two(a, b),
+        circle_from_two(a, c),
+        circle_from_two(b, c),
+    ];
+    return candidates
+        .into_iter()
+        .max_by(|x, y| x.radius.total_cmp(&y.radius))
+        .expect("three candidates");
+}
+
+impl ToBoundingBox for Circle {
+    fn bounding_box(&self) -> BoundingBox<f64> {
+        return BoundingBox::new(
+            self.center[0] - self.radius,
+            self.center[0] + self.radius,
+            self.center[1] - self.radius,
+            self.center[1] + self.radius,
+        );
+    }
+}
+
+impl BoundingBox<f64> {
+    /**
+    Returns the largest circle which is covered by `self`. Its center is the
+    center of `self` and its diameter is the smaller one of width and height.
+    Returns `None` if `self` is not [finite](BoundingBox::is_finite), since the
+    circle would not have a finite center.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Circle};
+
+    let bb = BoundingBox::new(0.0, 4.0, 0.0, 2.0);
+    assert_eq!(bb.inscribed_circle(), Some(Circle::new([2.0, 1.0], 1.0)));
+
+    let bb = BoundingBox::new(f64::NEG_INFINITY, f64::INFINITY, 0.0, 1.0);
+    assert_eq!(bb.inscribed_circle(), None);
+    ```
+     */
+    pub fn inscribed_circle(&self) -> Option<Circle> {
+        if !self.is_finite() {
+            return None;
+        }
+        return Circle::try_new(self.center(), 0.5 * self.width().min(self.height()));
+    }
+
+    /**
+    Returns the smallest circle which covers `self`, i.e. the circle through
+    all four corners of `self`. Returns `None` if `self` is not
+    [finite](BoundingBox::is_finite), since the circle would not have a finite
+    center.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Circle};
+
+    let bb = BoundingBox::new(0.0, 8.0, 0.0, 6.0);
+    assert_eq!(bb.circumscribed_circle(), Some(Circle::new([4.0, 3.0], 5.0)));
+
+    let bb = BoundingBox::new(0.0, 1.0, 0.0, f64::INFINITY);
+    assert_eq!(bb.circumscribed_circle(), None);
+    ```
+     */
+    pub fn circumscribed_circle(&self) -> Option<Circle> {
+        if !self.is_finite() {
+            return None;
+        }
+        return Circle::try_new(self.center(), 0.5 * self.width().hypot(self.height()));
+    }
+
+    /**
+    Returns true if `self` covers the circle completely.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, Circle};
+
+    let bb = BoundingBox::new(0.0, 2.0, 0.0, 2.0);
+    assert!(bb.covers_circle(&Circle::new([1.0, 1.0], 1.0)));
+    assert!(!bb.covers_circle(&Circle::new([1.0, 1.5], 1.0)));
+    ```
+     */
+    pub fn covers_circle(&self, circle: &Circle) -> bool {
+        return self.covers(&circle.bounding_box());
+    }
+}
@@ -0,0 +1,406 @@
+/*!
+Clipping of line segments, polylines and polygons to a [`BoundingBox`].
+ */
+
+use crate::BoundingBox;
+
+#[cfg(feature = "approx")]
+use approx::ulps_eq;
+
+impl BoundingBox<f64> {
+    /**
+    Clips the line segment from `start` to `end` to the box and returns the
+    start and end point of the part of the segment which is covered by the box.
+    If the segment misses the box, `None` is returned.
+
+    The clipping is done with the
+    [Liang–Barsky algorithm](https://en.wikipedia.org/wiki/Liang%E2%80%93Barsky_algorithm)
+    (see [`BoundingBox::segment_hit`]). Points where the segment crosses the
+    boundary of the box lie exactly on the respective side, and endpoints which
+    are covered by the box are returned unchanged. Since the box is closed, a
+    segment which only touches the box results in a degenerate segment.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 2.0, 0.0, 2.0);
+    assert_eq!(bb.clip_segment([-1.0, 1.0], [1.0, 1.0]), Some([[0.0, 1.0], [1.0, 1.0]]));
+    assert_eq!(bb.clip_segment([-1.0, -1.0], [3.0, 3.0]), Some([[0.0, 0.0], [2.0, 2.0]]));
+    assert_eq!(bb.clip_segment([3.0, 0.0], [3.0, 2.0]), None);
+    ```
+     */
+    pub fn clip_segment<P: Into<[f64; 2]>, Q: Into<[f64; 2]>>(
+        &self,
+        start: P,
+        end: Q,
+    ) -> Option<[[f64; 2]; 2]> {
+        return self
+            .clip_segment_flags(start.into(), end.into())
+            .map(|(segment, _, _)| segment);
+    }
+
+    /// Clips the segment and additionally returns whether the start and the
+    /// end point are covered by the box.
+    fn clip_segment_flags(
+        &self,
+        start: [f64; 2],
+        end: [f64; 2],
+    ) -> Option<([[f64; 2]; 2], bool, bool)> {
+        let hit = self.segment_hit(start, end)?;
+        let start_inside = hit.entry_side.is_none();
+        let end_inside = hit.exit_side.is_none();
+        let first = if start_inside { start } else { hit.entry };
+        let last = if end_inside {
+            end
+        } else {
+            hit.exit.expect("segments have a finite exit")
+        };
+        return Some(([first, last], start_inside, end_inside));
+    }
+
+    /**
+    Clips the polyline defined by `points` to the box and returns the pieces of
+    the polyline which are covered by the box.
+
+    Each piece is a list of consecutive points. A piece starts either at the
+    first point of the polyline or where the polyline enters the box, and ends
+    either at the last point of the polyline or where the polyline leaves the
+    box. Vertices of the polyline which are covered by the box are kept
+    unchanged. If a vertex lies on the boundary of the box, it is not repeated
+    as entry or exit point. A polyline consisting of a single point results in
+    a single piece if the point is covered by the box, and so does a polyline
+    which only touches the box in one vertex.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 2.0, 0.0, 2.0);
+    let polyline = [[-1.0, 1.0], [1.0, 1.0], [1.0, 3.0], [1.5, 3.0], [1.5, 1.5], [3.0, 1.5]];
+    let pieces = bb.clip_polyline(polyline);
+    assert_eq!(pieces, vec![
+        vec![[0.0, 1.0], [1.0, 1.0], [1.0, 2.0]],
+        vec![[1.5, 2.0], [1.5, 1.5], [2.0, 1.5]],
+    ]);
+    ```
+     */
+    pub fn clip_polyline<P: Into<[f64; 2]>, I: IntoIterator<Item = P>>(
+        &self,
+        points: I,
+    ) -> Vec<Vec<[f64; 2]>> {
+        let mut pieces = Vec::new();
+        let mut current: Vec<[f64; 2]> = Vec::new();
+        let mut points = points.into_iter().map(Into::into);
+        let mut previous: [f64; 2] = match points.next() {
+            Some(point) => point,
+            None => return pieces,
+        };
+        let mut single = true;
+        for point in points {
+            single = false;
+            match self.clip_segment_flags(previous, point) {
+                Some(([first, last], start_inside, end_inside)) => {
+                    let mut entered = false;
+                    if !start_inside || current.is_empty() {
+                        if !current.is_empty() {
+                            pieces.push(std::mem::take(&mut current));
+                        }
+                        current.push(first);
+                        entered = !start_inside;
+                    }
+                    // Entry and exit points coincide with vertices on the boundary
+                    let duplicate = (entered || !end_inside) && current.last() == Some(&last);
+                    if !duplicate {
+                        current.push(last);
+                    }
+                    if !end_inside {
+                        pieces.push(std::mem::take(&mut current));
+                    }
+                }
+                None => {
+                    if !current.is_empty() {
+                        pieces.push(std::mem::take(&mut current));
+                    }
+                }
+            }
+            previous = point;
+        }
+        if single && self.covers_point(previous) {
+            current.push(previous);
+        }
+        if !current.is_empty() {
+            pieces.push(current);
+        }
+        return pieces;
+    }
+
+    /**
+    Clips the polygon defined by `vertices` to the box and returns the vertices
+    of the clipped polygon.
+
+    The polygon is closed implicitly, i.e. the last vertex is connected to the
+    first one. The clipping is done with the
+    [Sutherland–Hodgman algorithm](https://en.wikipedia.org/wiki/Sutherland%E2%80%93Hodgman_algorithm),
+    which clips the polygon successively against the four sides of the box. The
+    polygon may be convex or concave; if a concave polygon is split into
+    multiple parts by the box, the parts are connected by degenerate edges
+    along the boundary of the box. If the polygon lies completely outside the
+    box, an empty vector is returned.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 2.0, 0.0, 2.0);
+
+    // Triangle whose tip is cut off by the top side
+    let triangle = [[0.0, 0.0], [2.0, 0.0], [1.0, 4.0]];
+    assert_eq!(
+        bb.clip_polygon(triangle),
+        vec![[0.5, 2.0], [0.0, 0.0], [2.0, 0.0], [1.5, 2.0]]
+    );
+
+    // Polygon outside the box
+    assert!(bb.clip_polygon([[3.0, 0.0], [4.0, 0.0], [4.0, 1.0]]).is_empty());
+    ```
+     */
+    pub fn clip_polygon<P: Into<[f64; 2]>, I: IntoIterator<Item = P>>(
+        &self,
+        vertices: I,
+    ) -> Vec<[f64; 2]> {
+        let polygon: Vec<[f64; 2]> = vertices.into_iter().map(Into::into).collect();
+        let polygon = clip_to_half_plane(&polygon, 0, self.xmin(), false);
+        let polygon = clip_to_half_plane(&polygon, 0, self.xmax(), true);
+        let polygon = clip_to_half_plane(&polygon, 1, self.ymin(), false);
+        return clip_to_half_plane(&polygon, 1, self.ymax(), true);
+    }
+
+    /**
+    Divides the box into a grid of `columns` x `rows` tiles of equal size and
+    clips the polygon defined by `vertices` to each tile.
+
+    Returns the column and row index of each tile the polygon overlaps together
+    with the vertices of the clipped polygon, see
+    [`BoundingBox::clip_polygon`]. Tiles which the polygon misses or only
+    touches are omitted. The column index counts from `xmin` to `xmax` and the
+    row index counts from `ymin` to `ymax`.
+
+    Instead of clipping the whole polygon against every tile, it is first split
+    into vertical strips and each strip is then split into tiles. Each split
+    only processes the remainder of the polygon which has not been assigned to
+    a strip / tile yet, and the splitting stops as soon as the remainder is
+    empty.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let grid = BoundingBox::new(0.0, 4.0, 0.0, 4.0);
+    let square = [[1.0, 1.0], [3.0, 1.0], [3.0, 2.0], [1.0, 2.0]];
+    let tiles = grid.clip_polygon_to_grid(square, 2, 2);
+
+    assert_eq!(tiles.len(), 2);
+    assert_eq!(tiles[0].0, [0, 0]);
+    assert_eq!(tiles[0].1, vec![[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]]);
+    assert_eq!(tiles[1].0, [1, 0]);
+    ```
+     */
+    pub fn clip_polygon_to_grid<P: Into<[f64; 2]>, I: IntoIterator<Item = P>>(
+        &self,
+        vertices: I,
+        columns: usize,
+        rows: usize,
+    ) -> Vec<([usize; 2], Vec<[f64; 2]>)> {
+        let mut tiles = Vec::new();
+        let split = |min: f64, max: f64, count: usize, idx: usize| {
+            if idx == count {
+                return max;
+            }
+            return min + (max - min) * (idx as f64) / (count as f64);
+        };
+
+        let mut remainder = self.clip_polygon(vertices);
+        for col in 0..columns {
+            if polygon_area(&remainder) == 0.0 {
+                break;
+            }
+            let x = split(self.xmin(), self.xmax(), columns, col + 1);
+            let mut strip = clip_to_half_plane(&remainder, 0, x, true);
+            remainder = clip_to_half_plane(&remainder, 0, x, false);
+            for row in 0..rows {
+                if polygon_area(&strip) == 0.0 {
+                    break;
+                }
+                let y = split(self.ymin(), self.ymax(), rows, row + 1);
+                let tile = clip_to_half_plane(&strip, 1, y, true);
+                strip = clip_to_half_plane(&strip, 1, y, false);
+                if polygon_area(&tile) != 0.0 {
+                    tiles.push(([col, row], tile));
+                }
+            }
+        }
+        return tiles;
+    }
+}
+
+/// Clips the polygon to the half-plane `point[axis] <= value` (if `keep_below`
+/// is true) or `point[axis] >= value` (otherwise).
+fn clip_to_half_plane(
+    polygon: &[[f64; 2]],
+    axis: usize,
+    value: f64,
+    keep_below: bool,
+) -> Vec<[f64; 2]> {
+    let inside = |point: &[f64; 2]| {
+        if keep_below {
+            return point[axis] <= value;
+        }
+        return point[axis] >= value;
+    };
+    let crossing = |from: &[f64; 2], to: &[f64; 2]| {
+        let t = (value - from[axis]) / (to[axis] - from[axis]);
+        let mut point = [
+            from[0] + t * (to[0] - from[0]),
+            from[1] + t * (to[1] - from[1]),
+        ];
+        point[axis] = value;
+        return point;
+    };
+
+    let mut clipped = Vec::with_capacity(polygon.len() + 2);
+    let mut previous = match polygon.last() {
+        Some(point) => point,
+        None => return clipped,
+    };
+    for current in polygon.iter() {
+        if inside(current) {
+            if !inside(previous) {
+                clipped.push(crossing(previous, current));
+            }
+            clipped.push(*current);
+        } else if inside(previous) {
+            clipped.push(crossing(previous, current));
+        }
+        previous = current;
+    }
+    return clipped;
+}
+
+/// Returns the unsigned area of the polygon (shoelace formula).
+fn polygon_area(polygon: &[[f64; 2]]) -> f64 {
+    let mut area = 0.0;
+    for (idx, current) in polygon.iter().enumerate() {
+        let next = &polygon[(idx + 1) % polygon.len()];
+        area += current[0] * next[1] - next[0] * current[1];
+    }
+    return (0.5 * area).abs();
+}
+
+#[cfg(feature = "approx")]
+impl BoundingBox<f64> {
+    /// Moves coordinates of `point` which are approximately equal to an
+    /// extremum of the box onto the extremum.
+    fn snap_to_sides(&self, point: [f64; 2], epsilon: f64, max_ulps: u32) -> [f64; 2] {
+        let snap = |val: f64, min: f64, max: f64| {
+            if ulps_eq!(val, min, epsilon = epsilon, max_ulps = max_ulps) {
+                return min;
+            }
+            if ulps_eq!(val, max, epsilon = epsilon, max_ulps = max_ulps) {
+                return max;
+            }
+            return val;
+        };
+        return [
+            snap(point[0], self.xmin(), self.xmax()),
+            snap(point[1], self.ymin(), self.ymax()),
+        ];
+    }
+
+    /**
+    Like [`BoundingBox::clip_segment`], but with absolute and ULPs tolerances.
+
+    Coordinates of `start` and `end` which are approximately equal to an
+    extremum of the box (see [`BoundingBox::approx_covers_point`]) are moved
+    onto the respective side before clipping. This way, segments running along
+    a side of the box are not lost due to roundoff errors.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+
+    // Segment slightly above the top side
+    assert_eq!(bb.clip_segment([0.2, 1.0001], [0.8, 1.0001]), None);
+    assert_eq!(
+        bb.approx_clip_segment([0.2, 1.0001], [0.8, 1.0001], 1e-3, 0),
+        Some([[0.2, 1.0], [0.8, 1.0]])
+    );
+    ```
+
+    # Features
+
+    This function uses the [`ulps_eq`] macro of the [approx] crate, therefore
+    the `approx` feature needs to be enabled.
+     */
+    pub fn approx_clip_segment<P: Into<[f64; 2]>, Q: Into<[f64; 2]>>(
+        &self,
+        start: P,
+        end: Q,
+        epsilon: f64,
+        max_ulps: u32,
+    ) -> Option<[[f64; 2]; 2]> {
+        return self.clip_segment(
+            self.snap_to_sides(start.into(), epsilon, max_ulps),
+            self.snap_to_sides(end.into(), epsilon, max_ulps),
+        );
+    }
+
+    /**
+    Like [`BoundingBox::clip_polyline`], but with absolute and ULPs tolerances.
+
+    Coordinates of the points which are approximately equal to an extremum of
+    the box are moved onto the respective side before clipping (see
+    [`BoundingBox::approx_clip_segment`]). This prevents a polyline which
+    touches the boundary from being split into separate pieces due to roundoff
+    errors.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    let polyline = [[0.2, 0.5], [0.5, 1.0 + 1e-12], [0.8, 0.5]];
+
+    assert_eq!(bb.clip_polyline(polyline).len(), 2);
+    assert_eq!(
+        bb.approx_clip_polyline(polyline, 1e-9, 0),
+        vec![vec![[0.2, 0.5], [0.5, 1.0], [0.8, 0.5]]]
+    );
+    ```
+
+    # Features
+
+    This function uses the [`ulps_eq`] macro of the [approx] crate, therefore
+    the `approx` feature needs to be enabled.
+     */
+    pub fn approx_clip_polyline<P: Into<[f64; 2]>, I: IntoIterator<Item = P>>(
+        &self,
+        points: I,
+        epsilon: f64,
+        max_ulps: u32,
+    ) -> Vec<Vec<[f64; 2]>> {
+        return self.clip_polyline(
+            points
+                .into_iter()
+                .map(|point| self.snap_to_sides(point.into(), epsilon, max_ulps)),
+        );
+    }
+}
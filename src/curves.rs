@@ -0,0 +1,612 @@
+/*!
+Curve primitives with exact (tight) [`ToBoundingBox`] implementations.
+ */
+
+use std::f64::consts::{PI, TAU};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{BoundingBox, ToBoundingBox};
+
+/// Moves `point` by `shift`.
+fn shift_point(point: &mut [f64; 2], shift: [f64; 2]) {
+    point[0] += shift[0];
+    point[1] += shift[1];
+}
+
+/// Creates the bounding box of a nonempty array of points.
+fn bounds_of<const N: usize>(points: [[f64; 2]; N]) -> BoundingBox<f64> {
+    return BoundingBox::from_points(points.into_iter()).expect("curve points must not be NaN");
+}
+
+/// Panics if any of the defining `values` of a curve is NaN. Without this
+/// check, NaN values would be silently dropped from some bounding boxes (e.g.
+/// a NaN endpoint of a [`LineSegment`]) but not from others.
+fn assert_no_nan(values: &[f64]) {
+    assert!(
+        !values.iter().any(|val| val.is_nan()),
+        "curve values must not be NaN"
+    );
+}
+
+/**
+A straight line segment from `start` to `end`.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, LineSegment};
+
+let segment = LineSegment::new([2.0, 0.0], [0.0, 1.0]);
+assert_eq!(BoundingBox::from(&segment), BoundingBox::new(0.0, 2.0, 0.0, 1.0));
+assert_eq!(segment.point_at(0.5), [1.0, 0.5]);
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineSegment {
+    /// Start point of the segment.
+    pub start: [f64; 2],
+    /// End point of the segment.
+    pub end: [f64; 2],
+}
+
+impl LineSegment {
+    /**
+    Creates a line segment from its start and end point.
+     */
+    pub fn new(start: [f64; 2], end: [f64; 2]) -> Self {
+        return LineSegment { start, end };
+    }
+
+    /**
+    Returns the point at the parameter `t`, where `t = 0` is the start and
+    `t = 1` is the end point.
+     */
+    pub fn point_at(&self, t: f64) -> [f64; 2] {
+        return [
+            self.start[0] + t * (self.end[0] - self.start[0]),
+            self.start[1] + t * (self.end[1] - self.start[1]),
+        ];
+    }
+
+    /**
+    Translates the segment by the given `shift`.
+     */
+    pub fn translate<P: Into<[f64; 2]>>(&mut self, shift: P) {
+        let shift: [f64; 2] = shift.into();
+        shift_point(&mut self.start, shift);
+        shift_point(&mut self.end, shift);
+    }
+}
+
+impl ToBoundingBox for LineSegment {
+    /**
+    Returns the bounding box spanned by start and end point.
+
+    # Panics
+    Panics if any of the points has a NaN coordinate.
+     */
+    fn bounding_box(&self) -> BoundingBox<f64> {
+        assert_no_nan([self.start, self.end].as_flattened());
+        return bounds_of([self.start, self.end]);
+    }
+}
+
+/// Returns true if the angle `t` lies within the sweep from `start` over
+/// `sweep` (which may be negative for clockwise arcs).
+fn in_sweep(t: f64, start: f64, sweep: f64) -> bool {
+    if sweep.abs() >= TAU {
+        return true;
+    }
+    if sweep >= 0.0 {
+        return (t - start).rem_euclid(TAU) <= sweep;
+    }
+    return (start - t).rem_euclid(TAU) <= -sweep;
+}
+
+/**
+An axis-aligned or rotated ellipse with the semi-axes `radii` (`[a, b]`).
+
+The ellipse is parametrized by the angle `t` as
+`center + R(rotation) * [a * cos(t), b * sin(t)]`, where `R(rotation)` is the
+counterclockwise rotation by `rotation` (in radians). A circle is an ellipse
+with equal radii.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, Ellipse};
+use std::f64::consts::FRAC_PI_2;
+
+let ellipse = Ellipse::new([0.0, 0.0], [2.0, 1.0], FRAC_PI_2);
+let bb = BoundingBox::from(&ellipse);
+assert!(bb.approx_eq(&BoundingBox::new(-1.0, 1.0, -2.0, 2.0), 1e-12, 0));
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ellipse {
+    /// Center of the ellipse.
+    pub center: [f64; 2],
+    /// Semi-axes of the ellipse along its local x- and y-axis.
+    pub radii: [f64; 2],
+    /// Counterclockwise rotation of the local x-axis in radians.
+    pub rotation: f64,
+}
+
+impl Ellipse {
+    /**
+    Creates an ellipse from its center, its semi-axes and its rotation.
+     */
+    pub fn new(center: [f64; 2], radii: [f64; 2], rotation: f64) -> Self {
+        return Ellipse {
+            center,
+            radii,
+            rotation,
+        };
+    }
+
+    /**
+    Returns the point at the parameter angle `t`.
+     */
+    pub fn point_at(&self, t: f64) -> [f64; 2] {
+        let (sin_r, cos_r) = self.rotation.sin_cos();
+        let (sin_t, cos_t) = t.sin_cos();
+        let x = self.radii[0] * cos_t;
+        let y = self.radii[1] * sin_t;
+        return [
+            self.center[0] + x * cos_r - y * sin_r,
+            self.center[1] + x * sin_r + y * cos_r,
+        ];
+    }
+
+    /// Returns the parameter angles at which the x- and the y-coordinate are
+    /// extremal (each extremum is also reached at the angle plus π).
+    fn extremal_angles(&self) -> [f64; 2] {
+        let (sin_r, cos_r) = self.rotation.sin_cos();
+        let [a, b] = self.radii;
+        return [(-b * sin_r).atan2(a * cos_r), (b * cos_r).atan2(a * sin_r)];
+    }
+
+    /**
+    Translates the ellipse by the given `shift`.
+     */
+    pub fn translate<P: Into<[f64; 2]>>(&mut self, shift: P) {
+        let shift: [f64; 2] = shift.into();
+        shift_point(&mut self.center, shift);
+    }
+}
+
+impl ToBoundingBox for Ellipse {
+    /**
+    Returns the tight bounding box of the ellipse.
+
+    # Panics
+    Panics if any of the values is NaN or if infinite values result in a NaN
+    extremum (e.g. an infinite rotation or an infinite radius around an
+    infinite center).
+     */
+    fn bounding_box(&self) -> BoundingBox<f64> {
+        assert_no_nan(&[
+            self.center[0],
+            self.center[1],
+            self.radii[0],
+            self.radii[1],
+            self.rotation,
+        ]);
+        let (sin_r, cos_r) = self.rotation.sin_cos();
+        let [a, b] = self.radii;
+        let hx = (a * cos_r).hypot(b * sin_r);
+        let hy = (a * sin_r).hypot(b * cos_r);
+        return BoundingBox::new(
+            self.center[0] - hx,
+            self.center[0] + hx,
+            self.center[1] - hy,
+            self.center[1] + hy,
+        );
+    }
+}
+
+/**
+A section of an [`Ellipse`] between the parameter angles `start_angle` and
+`start_angle + sweep`.
+
+A positive `sweep` runs counterclockwise, a negative one clockwise (for an
+ellipse which is not mirrored). If the absolute value of `sweep` is at least
+2π, the arc is the full ellipse.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, EllipticalArc};
+use std::f64::consts::PI;
+
+// Upper half of an ellipse
+let arc = EllipticalArc::new([0.0, 0.0], [2.0, 1.0], 0.0, 0.0, PI);
+let bb = BoundingBox::from(&arc);
+assert!(bb.approx_eq(&BoundingBox::new(-2.0, 2.0, 0.0, 1.0), 1e-12, 0));
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EllipticalArc {
+    /// The underlying ellipse.
+    pub ellipse: Ellipse,
+    /// Parameter angle of the start point in radians.
+    pub start_angle: f64,
+    /// Swept parameter angle in radians.
+    pub sweep: f64,
+}
+
+impl EllipticalArc {
+    /**
+    Creates an elliptical arc from the center, semi-axes and rotation of the
+    underlying ellipse as well as the start angle and the swept angle.
+     */
+    pub fn new(
+        center: [f64; 2],
+        radii: [f64; 2],
+        rotation: f64,
+        start_angle: f64,
+        sweep: f64,
+    ) -> Self {
+        return EllipticalArc {
+            ellipse: Ellipse::new(center, radii, rotation),
+            start_angle,
+            sweep,
+        };
+    }
+
+    /**
+    Returns the point at the parameter `t`, where `t = 0` is the start and
+    `t = 1` is the end point of the arc.
+     */
+    pub fn point_at(&self, t: f64) -> [f64; 2] {
+        return self.ellipse.point_at(self.start_angle + t * self.sweep);
+    }
+
+    /**
+    Translates the arc by the given `shift`.
+     */
+    pub fn translate<P: Into<[f64; 2]>>(&mut self, shift: P) {
+        self.ellipse.translate(shift);
+    }
+}
+
+impl ToBoundingBox for EllipticalArc {
+    /**
+    Returns the tight bounding box of the arc.
+
+    # Panics
+    Panics if any of the values is NaN or if infinite values result in NaN
+    end points (e.g. an infinite rotation or start angle).
+     */
+    fn bounding_box(&self) -> BoundingBox<f64> {
+        let ellipse = &self.ellipse;
+        assert_no_nan(&[
+            ellipse.center[0],
+            ellipse.center[1],
+            ellipse.radii[0],
+            ellipse.radii[1],
+            ellipse.rotation,
+            self.start_angle,
+            self.sweep,
+        ]);
+        let mut bb = bounds_of([self.point_at(0.0), self.point_at(1.0)]);
+        for angle in self.ellipse.extremal_angles() {
+            for t in [angle, angle + PI] {
+                if in_sweep(t, self.start_angle, self.sweep) {
+                    bb = bb.union(&BoundingBox::from(self.ellipse.point_at(t)));
+                }
+            }
+        }
+        return bb;
+    }
+}
+
+/**
+A section of a circle between the angles `start_angle` and
+`start_angle + sweep`, measured counterclockwise from the positive x-axis.
+
+A positive `sweep` runs counterclockwise, a negative one clockwise. If the
+absolute value of `sweep` is at least 2π, the arc is the full circle.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, CircularArc};
+use std::f64::consts::FRAC_PI_2;
+
+// Quarter circle in the first quadrant, clockwise
+let arc = CircularArc::new([0.0, 0.0], 2.0, FRAC_PI_2, -FRAC_PI_2);
+let bb = BoundingBox::from(&arc);
+assert!(bb.approx_eq(&BoundingBox::new(0.0, 2.0, 0.0, 2.0), 1e-12, 0));
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CircularArc {
+    /// Center of the circle.
+    pub center: [f64; 2],
+    /// Radius of the circle.
+    pub radius: f64,
+    /// Angle of the start point in radians.
+    pub start_angle: f64,
+    /// Swept angle in radians.
+    pub sweep: f64,
+}
+
+impl CircularArc {
+    /**
+    Creates a circular arc from the center and radius of the circle as well as
+    the start angle and the swept angle.
+     */
+    pub fn new(center: [f64; 2], radius: f64, start_angle: f64, sweep: f64) -> Self {
+        return CircularArc {
+            center,
+            radius,
+            start_angle,
+            sweep,
+        };
+    }
+
+    /**
+    Returns the point at the parameter `t`, where `t = 0` is the start and
+    `t = 1` is the end point of the arc.
+     */
+    pub fn point_at(&self, t: f64) -> [f64; 2] {
+        let (sin, cos) = (self.start_angle + t * self.sweep).sin_cos();
+        return [
+            self.center[0] + self.radius * cos,
+            self.center[1] + self.radius * sin,
+        ];
+    }
+
+    /**
+    Translates the arc by the given `shift`.
+     */
+    pub fn translate<P: Into<[f64; 2]>>(&mut self, shift: P) {
+        let shift: [f64; 2] = shift.into();
+        shift_point(&mut self.center, shift);
+    }
+}
+
+impl ToBoundingBox for CircularArc {
+    /**
+    Returns the tight bounding box of the arc.
+
+    # Panics
+    Panics if any of the values is NaN or if infinite values result in NaN
+    end points (e.g. an infinite start angle).
+     */
+    fn bounding_box(&self) -> BoundingBox<f64> {
+        assert_no_nan(&[
+            self.center[0],
+            self.center[1],
+            self.radius,
+            self.start_angle,
+            self.sweep,
+        ]);
+        let mut bb = bounds_of([self.point_at(0.0), self.point_at(1.0)]);
+        // The extremas of the full circle, in the order right, top, left, bottom
+        let [cx, cy] = self.center;
+        let r = self.radius;
+        let extremas = [[cx + r, cy], [cx, cy + r], [cx - r, cy], [cx, cy - r]];
+        for (quadrant, point) in extremas.into_iter().enumerate() {
+            if in_sweep(quadrant as f64 * PI / 2.0, self.start_angle, self.sweep) {
+                bb = bb.union(&BoundingBox::from(point));
+            }
+        }
+        return bb;
+    }
+}
+
+/**
+A quadratic Bézier curve defined by a start point, a control point and an end
+point.
+
+The bounding box is tight: Instead of simply enclosing the control points, the
+extremas of the curve are found by solving for the roots of its derivative.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, QuadraticBezier};
+
+let curve = QuadraticBezier::new([0.0, 0.0], [1.0, 2.0], [2.0, 0.0]);
+assert_eq!(BoundingBox::from(&curve), BoundingBox::new(0.0, 2.0, 0.0, 1.0));
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuadraticBezier {
+    /// Start point of the curve.
+    pub start: [f64; 2],
+    /// Control point of the curve.
+    pub control: [f64; 2],
+    /// End point of the curve.
+    pub end: [f64; 2],
+}
+
+impl QuadraticBezier {
+    /**
+    Creates a quadratic Bézier curve from its start, control and end point.
+     */
+    pub fn new(start: [f64; 2], control: [f64; 2], end: [f64; 2]) -> Self {
+        return QuadraticBezier {
+            start,
+            control,
+            end,
+        };
+    }
+
+    /**
+    Returns the point at the parameter `t`, where `t = 0` is the start and
+    `t = 1` is the end point.
+     */
+    pub fn point_at(&self, t: f64) -> [f64; 2] {
+        let s = 1.0 - t;
+        let eval = |axis: usize| {
+            s * s * self.start[axis] + 2.0 * s * t * self.control[axis] + t * t * self.end[axis]
+        };
+        return [eval(0), eval(1)];
+    }
+
+    /**
+    Translates the curve by the given `shift`.
+     */
+    pub fn translate<P: Into<[f64; 2]>>(&mut self, shift: P) {
+        let shift: [f64; 2] = shift.into();
+        shift_point(&mut self.start, shift);
+        shift_point(&mut self.control, shift);
+        shift_point(&mut self.end, shift);
+    }
+}
+
+impl ToBoundingBox for QuadraticBezier {
+    /**
+    Returns the tight bounding box of the curve.
+
+    # Panics
+    Panics if any of the points has a NaN coordinate.
+     */
+    fn bounding_box(&self) -> BoundingBox<f64> {
+        assert_no_nan([self.start, self.control, self.end].as_flattened());
+        let mut bb = bounds_of([self.start, self.end]);
+        for axis in 0..2 {
+            // Root of the derivative 2 * ((p0 - 2 p1 + p2) t + (p1 - p0))
+            let denom = self.start[axis] - 2.0 * self.control[axis] + self.end[axis];
+            if denom != 0.0 {
+                let t = (self.start[axis] - self.control[axis]) / denom;
+                if t > 0.0 && t < 1.0 {
+                    bb = bb.union(&BoundingBox::from(self.point_at(t)));
+                }
+            }
+        }
+        return bb;
+    }
+}
+
+/**
+A cubic Bézier curve defined by a start point, two control points and an end
+point.
+
+The bounding box is tight: Instead of simply enclosing the control points, the
+extremas of the curve are found by solving for the roots of its derivative.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, CubicBezier};
+
+let curve = CubicBezier::new([0.0, 0.0], [0.0, 4.0], [3.0, 4.0], [3.0, 0.0]);
+assert_eq!(BoundingBox::from(&curve), BoundingBox::new(0.0, 3.0, 0.0, 3.0));
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CubicBezier {
+    /// Start point of the curve.
+    pub start: [f64; 2],
+    /// First control point of the curve.
+    pub control1: [f64; 2],
+    /// Second control point of the curve.
+    pub control2: [f64; 2],
+    /// End point of the curve.
+    pub end: [f64; 2],
+}
+
+impl CubicBezier {
+    /**
+    Creates a cubic Bézier curve from its start point, its two control points
+    and its end point.
+     */
+    pub fn new(start: [f64; 2], control1: [f64; 2], control2: [f64; 2], end: [f64; 2]) -> Self {
+        return CubicBezier {
+            start,
+            control1,
+            control2,
+            end,
+        };
+    }
+
+    /**
+    Returns the point at the parameter `t`, where `t = 0` is the start and
+    `t = 1` is the end point.
+     */
+    pub fn point_at(&self, t: f64) -> [f64; 2] {
+        let s = 1.0 - t;
+        let eval = |axis: usize| {
+            s * s * s * self.start[axis]
+                + 3.0 * s * s * t * self.control1[axis]
+                + 3.0 * s * t * t * self.control2[axis]
+                + t * t * t * self.end[axis]
+        };
+        return [eval(0), eval(1)];
+    }
+
+    /**
+    Translates the curve by the given `shift`.
+     */
+    pub fn translate<P: Into<[f64; 2]>>(&mut self, shift: P) {
+        let shift: [f64; 2] = shift.into();
+        shift_point(&mut self.start, shift);
+        shift_point(&mut self.control1, shift);
+        shift_point(&mut self.control2, shift);
+        shift_point(&mut self.end, shift);
+    }
+}
+
+impl ToBoundingBox for CubicBezier {
+    /**
+    Returns the tight bounding box of the curve.
+
+    # Panics
+    Panics if any of the points has a NaN coordinate.
+     */
+    fn bounding_box(&self) -> BoundingBox<f64> {
+        assert_no_nan([self.start, self.control1, self.control2, self.end].as_flattened());
+        let mut bb = bounds_of([self.start, self.end]);
+        for axis in 0..2 {
+            let [p0, p1, p2, p3] = [
+                self.start[axis],
+                self.control1[axis],
+                self.control2[axis],
+                self.end[axis],
+            ];
+            // The derivative divided by 3 is a t² + b t + c
+            let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+            let b = 2.0 * (p0 - 2.0 * p1 + p2);
+            let c = p1 - p0;
+            for t in quadratic_roots(a, b, c).into_iter().flatten() {
+                if t > 0.0 && t < 1.0 {
+                    bb = bb.union(&BoundingBox::from(self.point_at(t)));
+                }
+            }
+        }
+        return bb;
+    }
+}
+
+/// Returns the real roots of `a x² + b x + c`. The numerically stable form
+/// avoids cancellation if `b² >> 4ac`.
+fn quadratic_roots(a: f64, b: f64, c: f64) -> [Option<f64>; 2] {
+    if a == 0.0 {
+        if b == 0.0 {
+            return [None, None];
+        }
+        return [Some(-c / b), None];
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return [None, None];
+    }
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+    if q == 0.0 {
+        // b and the discriminant are zero: double root at zero
+        return [Some(0.0), None];
+    }
+    return [Some(q / a), Some(c / q)];
+}
@@ -0,0 +1,142 @@
+/*!
+Iterators returned by [`BoundingBox::difference`] and
+[`BoundingBox::disjoint_cover`].
+ */
+
+use crate::{BoundingBox, Scalar};
+
+/**
+Iterator over the (up to four) non-overlapping bounding boxes which make up the
+difference of two bounding boxes.
+
+This struct is created by [`BoundingBox::difference`]. It does not allocate.
+ */
+#[derive(Debug, Clone)]
+pub struct Difference<T = f64> {
+    pieces: [Option<BoundingBox<T>>; 4],
+    index: usize,
+}
+
+impl<T: Scalar> Difference<T> {
+    pub(crate) fn new(minuend: &BoundingBox<T>, subtrahend: &BoundingBox<T>) -> Self {
+        let mut pieces = [None; 4];
+        if !minuend.overlaps(subtrahend) {
+            pieces[0] = Some(*minuend);
+        } else if !subtrahend.covers(minuend) {
+            // Left and right strips span the full height of the minuend, the
+            // bottom and top strips are limited to the remaining middle part.
+            let xmin = if minuend.xmin() < subtrahend.xmin() {
+                pieces[0] = Some(BoundingBox::new(
+                    minuend.xmin(),
+                    subtrahend.xmin(),
+                    minuend.ymin(),
+                    minuend.ymax(),
+                ));
+                subtrahend.xmin()
+            } else {
+                minuend.xmin()
+            };
+            let xmax = if subtrahend.xmax() < minuend.xmax() {
+                pieces[1] = Some(BoundingBox::new(
+                    subtrahend.xmax(),
+                    minuend.xmax(),
+                    minuend.ymin(),
+                    minuend.ymax(),
+                ));
+                subtrahend.xmax()
+            } else {
+                minuend.xmax()
+            };
+            if minuend.ymin() < subtrahend.ymin() {
+                pieces[2] = Some(BoundingBox::new(
+                    xmin,
+                    xmax,
+                    minuend.ymin(),
+                    subtrahend.ymin(),
+                ));
+            }
+            if subtrahend.ymax() < minuend.ymax() {
+                pieces[3] = Some(BoundingBox::new(
+                    xmin,
+                    xmax,
+                    subtrahend.ymax(),
+                    minuend.ymax(),
+                ));
+            }
+        }
+        return Difference { pieces, index: 0 };
+    }
+}
+
+impl<T: Scalar> Iterator for Difference<T> {
+    type Item = BoundingBox<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.pieces.len() {
+            let piece = self.pieces[self.index].take();
+            self.index += 1;
+            if piece.is_some() {
+                return piece;
+            }
+        }
+        return None;
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.pieces[self.index..]
+            .iter()
+            .filter(|piece| piece.is_some())
+            .count();
+        return (remaining, Some(remaining));
+    }
+}
+
+impl<T: Scalar> ExactSizeIterator for Difference<T> {}
+
+/**
+Iterator over non-overlapping bounding boxes which cover the same area as a set
+of possibly overlapping input boxes.
+
+This struct is created by [`BoundingBox::disjoint_cover`]. The boxes are
+produced lazily: each input box is reduced by all input boxes preceding it,
+which means that the first input box is always yielded unchanged.
+ */
+#[derive(Debug, Clone)]
+pub struct DisjointCover<T = f64> {
+    boxes: Vec<BoundingBox<T>>,
+    next_box: usize,
+    fragments: Vec<BoundingBox<T>>,
+}
+
+impl<T: Scalar> DisjointCover<T> {
+    pub(crate) fn new(boxes: Vec<BoundingBox<T>>) -> Self {
+        return DisjointCover {
+            boxes,
+            next_box: 0,
+            fragments: Vec::new(),
+        };
+    }
+}
+
+impl<T: Scalar> Iterator for DisjointCover<T> {
+    type Item = BoundingBox<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.fragments.is_empty() {
+            let bb = *self.boxes.get(self.next_box)?;
+            self.fragments.push(bb);
+            for preceding in self.boxes[..self.next_box].iter() {
+                if self.fragments.is_empty() {
+                    break;
+                }
+                self.fragments = self
+                    .fragments
+                    .iter()
+                    .flat_map(|fragment| fragment.difference(preceding))
+                    .collect();
+            }
+            self.next_box += 1;
+        }
+        return self.fragments.pop();
+    }
+}
@@ -0,0 +1,122 @@
+/*!
+Defines the [`BoundingBoxError`] returned by the fallible constructors and
+setters of [`BoundingBox`](crate::BoundingBox).
+ */
+
+use std::fmt;
+
+/**
+One of the four values defining a [`BoundingBox`](crate::BoundingBox).
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Extremum {
+    /// The minimum x-value.
+    Xmin,
+    /// The maximum x-value.
+    Xmax,
+    /// The minimum y-value.
+    Ymin,
+    /// The maximum y-value.
+    Ymax,
+}
+
+impl fmt::Display for Extremum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Extremum::Xmin => "xmin",
+            Extremum::Xmax => "xmax",
+            Extremum::Ymin => "ymin",
+            Extremum::Ymax => "ymax",
+        };
+        return f.write_str(name);
+    }
+}
+
+/**
+Describes why a set of extremas does not define a valid
+[`BoundingBox`](crate::BoundingBox).
+
+The offending values are stored as `f64` (converted via
+[`Scalar::to_f64`](crate::Scalar::to_f64)), so that the error type is
+independent of the scalar type of the bounding box.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, BoundingBoxError, Extremum};
+
+assert_eq!(
+    BoundingBox::new_checked(2.0, 1.0, 0.0, 1.0),
+    Err(BoundingBoxError::InvertedX { xmin: 2.0, xmax: 1.0 })
+);
+assert_eq!(
+    BoundingBox::new_checked(0.0, 1.0, f64::NAN, 1.0),
+    Err(BoundingBoxError::NaN(Extremum::Ymin))
+);
+
+let err = BoundingBox::new_checked(0.0, 1.0, 3.0, 2.0).unwrap_err();
+assert_eq!(err.to_string(), "invalid bounding box: ymin (3) is larger than ymax (2)");
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoundingBoxError {
+    /// The minimum x-value is larger than the maximum x-value.
+    InvertedX {
+        /// The given minimum x-value.
+        xmin: f64,
+        /// The given maximum x-value.
+        xmax: f64,
+    },
+    /// The minimum y-value is larger than the maximum y-value.
+    InvertedY {
+        /// The given minimum y-value.
+        ymin: f64,
+        /// The given maximum y-value.
+        ymax: f64,
+    },
+    /// The given extremum is NaN.
+    NaN(Extremum),
+    /// The element with the given index of an input iterator (e.g. a point in
+    /// [`BoundingBox::from_points_checked`](crate::BoundingBox::from_points_checked))
+    /// contains NaN.
+    NaNInput {
+        /// Index of the element within the iterator.
+        index: usize,
+    },
+    /// The given extremum is infinite, which is not allowed in this context
+    /// (e.g. in [`BoundingBox::new_finite_checked`](crate::BoundingBox::new_finite_checked)).
+    Infinite(Extremum),
+}
+
+impl fmt::Display for BoundingBoxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoundingBoxError::InvertedX { xmin, xmax } => {
+                return write!(
+                    f,
+                    "invalid bounding box: xmin ({xmin}) is larger than xmax ({xmax})"
+                );
+            }
+            BoundingBoxError::InvertedY { ymin, ymax } => {
+                return write!(
+                    f,
+                    "invalid bounding box: ymin ({ymin}) is larger than ymax ({ymax})"
+                );
+            }
+            BoundingBoxError::NaN(extremum) => {
+                return write!(f, "invalid bounding box: {extremum} is NaN");
+            }
+            BoundingBoxError::NaNInput { index } => {
+                return write!(
+                    f,
+                    "invalid bounding box: input element {index} contains NaN"
+                );
+            }
+            BoundingBoxError::Infinite(extremum) => {
+                return write!(f, "invalid bounding box: {extremum} is infinite");
+            }
+        }
+    }
+}
+
+impl std::error::Error for BoundingBoxError {}
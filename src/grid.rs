@@ -0,0 +1,216 @@
+/*!
+Defines the [`SpatialGrid`] broad-phase collision structure.
+ */
+
+use crate::{BoundingBox, IntersectionTest, Scalar, ToBoundingBox};
+
+/**
+A uniform grid (also known as spatial hash) which buckets items implementing
+[`ToBoundingBox`] into square cells.
+
+The grid divides a world [`BoundingBox`] into cells of a fixed size. Each item
+is registered in all cells its bounding box intersects. Candidate pairs of
+colliding items only need to be searched within the individual cells, which
+makes the grid a very efficient broad-phase for sets of items with roughly
+uniform sizes (e.g. particles or tiles), provided that the cell size is
+comparable to the item size.
+
+Items outside the world box are registered in the nearest border cells, so
+they are still handled correctly, albeit less efficiently.
+
+Items are identified by their insertion index. For simulations where all items
+move each frame, the grid can be [cleared](SpatialGrid::clear) and refilled
+without reallocating the cells.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, IntersectionTest, SpatialGrid};
+
+let mut grid = SpatialGrid::new(BoundingBox::new(0.0, 10.0, 0.0, 10.0), 1.0);
+grid.insert(BoundingBox::new(0.5, 1.5, 0.5, 1.5));
+grid.insert(BoundingBox::new(1.0, 2.0, 1.0, 2.0));
+grid.insert(BoundingBox::new(2.0, 3.0, 1.0, 2.0)); // Touches the second box
+grid.insert(BoundingBox::new(7.0, 8.0, 7.0, 8.0));
+
+let mut pairs: Vec<_> = grid.pairs(IntersectionTest::Intersects).collect();
+pairs.sort();
+assert_eq!(pairs, vec![(0, 1), (1, 2)]);
+
+// Exclude pairs which are just touching
+let pairs: Vec<_> = grid.pairs(IntersectionTest::Overlaps).collect();
+assert_eq!(pairs, vec![(0, 1)]);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct SpatialGrid<T, S = f64> {
+    world: BoundingBox<S>,
+    cell_size: S,
+    cols: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+    items: Vec<(BoundingBox<S>, T)>,
+}
+
+impl<T: ToBoundingBox<S>, S: Scalar> SpatialGrid<T, S> {
+    /**
+    Creates an empty grid covering `world` with square cells of edge length
+    `cell_size`.
+
+    # Panics
+    Panics if `cell_size` is not positive or if `world` is not finite.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, SpatialGrid};
+
+    let grid: SpatialGrid<BoundingBox> = SpatialGrid::new(BoundingBox::new(0.0, 10.0, 0.0, 5.0), 2.0);
+    assert_eq!(grid.dimensions(), [5, 3]);
+    ```
+     */
+    pub fn new(world: BoundingBox<S>, cell_size: S) -> Self {
+        assert!(cell_size > S::ZERO, "cell size must be positive");
+        assert!(world.is_finite(), "world bounding box must be finite");
+        let count = |extent: S| ((extent / cell_size).to_f64().ceil() as usize).max(1);
+        let cols = count(world.width());
+        let rows = count(world.height());
+        return SpatialGrid {
+            world,
+            cell_size,
+            cols,
+            rows,
+            cells: vec![Vec::new(); cols * rows],
+            items: Vec::new(),
+        };
+    }
+
+    /**
+    Returns the world box covered by the grid.
+     */
+    pub fn world(&self) -> BoundingBox<S> {
+        return self.world;
+    }
+
+    /**
+    Returns the edge length of the cells.
+     */
+    pub fn cell_size(&self) -> S {
+        return self.cell_size;
+    }
+
+    /**
+    Returns the number of columns and rows of the grid.
+     */
+    pub fn dimensions(&self) -> [usize; 2] {
+        return [self.cols, self.rows];
+    }
+
+    /**
+    Returns the number of items stored in the grid.
+     */
+    pub fn len(&self) -> usize {
+        return self.items.len();
+    }
+
+    /**
+    Returns true if the grid does not contain any items.
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.items.is_empty();
+    }
+
+    /**
+    Returns the item with the given index.
+     */
+    pub fn get(&self, index: usize) -> Option<&T> {
+        return self.items.get(index).map(|(_, item)| item);
+    }
+
+    /**
+    Removes all items from the grid while keeping the allocated cells.
+     */
+    pub fn clear(&mut self) {
+        for cell in self.cells.iter_mut() {
+            cell.clear();
+        }
+        self.items.clear();
+    }
+
+    /**
+    Inserts an item into all cells its bounding box intersects and returns the
+    index of the item.
+     */
+    pub fn insert(&mut self, item: T) -> usize {
+        let bb = item.bounding_box();
+        let index = self.items.len();
+        let [col_min, row_min] = self.cell_of(bb.xmin(), bb.ymin());
+        let [col_max, row_max] = self.cell_of(bb.xmax(), bb.ymax());
+        for row in row_min..=row_max {
+            for col in col_min..=col_max {
+                self.cells[row * self.cols + col].push(index);
+            }
+        }
+        self.items.push((bb, item));
+        return index;
+    }
+
+    /// Returns the column and row of the cell covering the given coordinates,
+    /// clamped to the grid.
+    fn cell_of(&self, x: S, y: S) -> [usize; 2] {
+        let index = |val: S, min: S, count: usize| {
+            let idx = ((val - min) / self.cell_size).to_f64().floor();
+            if idx <= 0.0 {
+                return 0;
+            }
+            return (idx as usize).min(count - 1);
+        };
+        return [
+            index(x, self.world.xmin(), self.cols),
+            index(y, self.world.ymin(), self.rows),
+        ];
+    }
+
+    /**
+    Returns an iterator over all pairs of item indices `(i, j)` with `i < j`
+    whose bounding boxes are colliding according to `test`.
+
+    Each pair is reported exactly once, even if the two items share multiple
+    cells. The pairs are not sorted.
+     */
+    pub fn pairs(&self, test: IntersectionTest) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return self
+            .cells
+            .iter()
+            .enumerate()
+            .flat_map(move |(cell_idx, cell)| {
+                (0..cell.len()).flat_map(move |a| {
+                    (a + 1..cell.len()).filter_map(move |b| {
+                        let (i, j) = (cell[a].min(cell[b]), cell[a].max(cell[b]));
+                        let (bb_i, bb_j) = (&self.items[i].0, &self.items[j].0);
+                        if !test.evaluate(bb_i, bb_j) {
+                            return None;
+                        }
+
+                        // Report the pair only in the cell containing the
+                        // lower left corner of the intersection region
+                        let x = if bb_i.xmin() > bb_j.xmin() {
+                            bb_i.xmin()
+                        } else {
+                            bb_j.xmin()
+                        };
+                        let y = if bb_i.ymin() > bb_j.ymin() {
+                            bb_i.ymin()
+                        } else {
+                            bb_j.ymin()
+                        };
+                        let [col, row] = self.cell_of(x, y);
+                        if row * self.cols + col != cell_idx {
+                            return None;
+                        }
+                        return Some((i, j));
+                    })
+                })
+            });
+    }
+}
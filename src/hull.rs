@@ -0,0 +1,133 @@
+/*!
+Convex hull of a point set using
+[Andrew's monotone chain algorithm](https://en.wikibooks.org/wiki/Algorithm_Implementation/Geometry/Convex_hull/Monotone_chain).
+ */
+
+use std::cmp::Ordering;
+
+use crate::BoundingBox;
+
+/// Returns the cross product of `b - a` and `c - a`, which is positive if
+/// `a`, `b` and `c` form a counterclockwise turn.
+fn cross(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    return (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+}
+
+/**
+Returns the vertices of the convex hull of `points` in counterclockwise order,
+starting with the point with the smallest x-value (and the smallest y-value
+among those).
+
+The hull is computed with Andrew's monotone chain algorithm in `O(n log n)`.
+The result is robust with respect to degenerate inputs:
+- Duplicate points and points lying on a hull edge (collinear points) are not
+  part of the hull.
+- If all points are collinear, the hull consists of the two endpoints of the
+  point set. If all points are identical, it consists of a single point. If
+  `points` is empty, the hull is empty as well.
+- Points with NaN coordinates are ignored.
+
+The iterator is accepted in the same form as for [`BoundingBox::from_points`].
+To obtain both the bounding box and the hull of a point set in a single pass,
+use [`BoundingBox::from_points_with_hull`].
+
+# Examples
+
+```
+use bounding_box::convex_hull;
+
+let points = [[0.0, 0.0], [2.0, 0.0], [1.0, 1.0], [2.0, 2.0], [0.0, 2.0], [1.0, 0.0]];
+let hull = convex_hull(points.into_iter());
+assert_eq!(hull, vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+
+// Collinear points
+let hull = convex_hull([[1.0, 1.0], [0.0, 0.0], [3.0, 3.0]].into_iter());
+assert_eq!(hull, vec![[0.0, 0.0], [3.0, 3.0]]);
+```
+ */
+pub fn convex_hull<P: Into<[f64; 2]>, I: Iterator<Item = P>>(points: I) -> Vec<[f64; 2]> {
+    return monotone_chain(points.map(Into::into).collect());
+}
+
+/// Computes the convex hull of the given points (see [`convex_hull`]).
+fn monotone_chain(mut points: Vec<[f64; 2]>) -> Vec<[f64; 2]> {
+    points.retain(|point| !point[0].is_nan() && !point[1].is_nan());
+    points.sort_by(|a, b| {
+        a[0].partial_cmp(&b[0])
+            .unwrap_or(Ordering::Equal)
+            .then(a[1].partial_cmp(&b[1]).unwrap_or(Ordering::Equal))
+    });
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull: Vec<[f64; 2]> = Vec::with_capacity(points.len() + 1);
+    let push = |hull: &mut Vec<[f64; 2]>, point: [f64; 2], min_len: usize| {
+        while hull.len() >= min_len
+            && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    };
+
+    // Lower hull from left to right
+    for point in points.iter() {
+        push(&mut hull, *point, 2);
+    }
+    // Upper hull from right to left, without removing points of the lower hull
+    let min_len = hull.len() + 1;
+    for point in points.iter().rev().skip(1) {
+        push(&mut hull, *point, min_len);
+    }
+    // The last point equals the first one
+    hull.pop();
+    return hull;
+}
+
+impl BoundingBox<f64> {
+    /**
+    Creates a bounding box and the [convex hull](convex_hull) from an iterator
+    over vertices, consuming the iterator only once.
+
+    Points with NaN coordinates are ignored for both the bounding box and the
+    hull. If the iterator does not yield any other points, this function
+    returns `None`.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let verts = vec![[1.0, 0.0], [-5.0, 2.0], [3.0, -12.3], [7.0, 0.0], [2.0, 1.0]];
+    let (bb, hull) = BoundingBox::from_points_with_hull(verts.into_iter()).unwrap();
+
+    assert_eq!(bb, BoundingBox::new(-5.0, 7.0, -12.3, 2.0));
+    assert_eq!(hull, vec![[-5.0, 2.0], [3.0, -12.3], [7.0, 0.0], [2.0, 1.0]]);
+    ```
+     */
+    pub fn from_points_with_hull<P: Into<[f64; 2]>, I: Iterator<Item = P>>(
+        verts: I,
+    ) -> Option<(Self, Vec<[f64; 2]>)> {
+        let mut points: Vec<[f64; 2]> = Vec::with_capacity(verts.size_hint().0);
+        let mut bb: Option<Self> = None;
+        for vert in verts {
+            let point: [f64; 2] = vert.into();
+            if point[0].is_nan() || point[1].is_nan() {
+                continue;
+            }
+            bb = match bb {
+                Some(bb) => Some(BoundingBox::new(
+                    bb.xmin().min(point[0]),
+                    bb.xmax().max(point[0]),
+                    bb.ymin().min(point[1]),
+                    bb.ymax().max(point[1]),
+                )),
+                None => Some(BoundingBox::from(point)),
+            };
+            points.push(point);
+        }
+        return bb.map(|bb| (bb, monotone_chain(points)));
+    }
+}
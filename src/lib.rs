@@ -1,6 +1,17 @@
 /*!
 [`ToBoundingBox`]: crate::ToBoundingBox
 [`BoundingBox`]: crate::BoundingBox
+[`Scalar`]: crate::Scalar
+[`BoundingBox3`]: crate::BoundingBox3
+[`ToBoundingBox3`]: crate::ToBoundingBox3
+[`RTree`]: crate::RTree
+[`QuadTree`]: crate::QuadTree
+[`SpatialGrid`]: crate::SpatialGrid
+[`Bvh`]: crate::Bvh
+[`OrientedBoundingBox`]: crate::OrientedBoundingBox
+[`Circle`]: crate::Circle
+[`MaybeEmptyBox`]: crate::MaybeEmptyBox
+[`Path`]: crate::Path
 [`covers_point`]: crate::BoundingBox::covers_point
 [`approx_covers_point`]: crate::BoundingBox::approx_covers_point
 
@@ -9,12 +20,52 @@ A library for rectilinear, 2-dimensional bounding boxes.
  */
 #![doc = include_str!("../docs/main.md")]
 #![deny(missing_docs)]
+#![allow(clippy::needless_return)]
+
+mod bounding_box3;
+mod bvh;
+mod circle;
+mod clip;
+mod curves;
+mod difference;
+mod error;
+mod grid;
+mod hull;
+mod maybe_empty;
+mod ops;
+mod oriented;
+mod path;
+mod quadtree;
+mod ray;
+#[cfg(feature = "serde")]
+pub mod representation;
+mod rtree;
+mod scalar;
+mod sweep;
+mod transform;
+
+pub use bounding_box3::{BoundingBox3, ToBoundingBox3};
+pub use bvh::{Bvh, BvhKey, BvhSplit};
+pub use circle::Circle;
+pub use curves::{CircularArc, CubicBezier, Ellipse, EllipticalArc, LineSegment, QuadraticBezier};
+pub use difference::{Difference, DisjointCover};
+pub use error::{BoundingBoxError, Extremum};
+pub use grid::SpatialGrid;
+pub use hull::convex_hull;
+pub use maybe_empty::MaybeEmptyBox;
+pub use oriented::OrientedBoundingBox;
+pub use path::{Path, PathSegment};
+pub use quadtree::{QuadTree, QuadTreeKey};
+pub use ray::{BoxSide, RayHit};
+pub use rtree::RTree;
+pub use scalar::Scalar;
+pub use sweep::{sweep_and_prune, sweep_and_prune_bipartite};
 
 #[cfg(feature = "approx")]
-use approx::ulps_eq;
+use approx::{UlpsEq, ulps_eq};
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
 /**
 A rectilinear, 2-dimensional [bounding box](https://en.wikipedia.org/wiki/Minimum_bounding_rectangle).
@@ -26,29 +77,135 @@ from the constructors [`new`](BoundingBox::new) or
 [`try_new`](BoundingBox::try_new). The values defining a bounding box (`xmin`,
 `xmax`, `ymin`, `ymax`) are called "extremas".
 
-Since a bounding box only consists of four f64 values (32 bytes), it is cheap to
-copy, hence it implements the
+The type of the extremas is given by the generic parameter `T`, which defaults
+to `f64`. Any type implementing [`Scalar`] can be used, e.g. `f32` for GPU-side
+geometry or `i32` / `i64` for pixel grids:
+
+```
+use bounding_box::BoundingBox;
+
+let bb_f64 = BoundingBox::new(0.0, 1.0, 0.0, 1.0); // BoundingBox<f64>
+let bb_f32 = BoundingBox::new(0.0f32, 1.0, 0.0, 1.0);
+let bb_i32 = BoundingBox::new(0, 640, 0, 480);
+
+assert_eq!(bb_f64.width(), 1.0);
+assert_eq!(bb_f32.width(), 1.0f32);
+assert_eq!(bb_i32.center(), [320, 240]);
+```
+
+Bounding boxes of different scalar types can be converted into each other with
+[`convert`](BoundingBox::convert) (lossless) and [`cast`](BoundingBox::cast)
+(possibly lossy).
+
+Since a bounding box only consists of four scalar values (e.g. 32 bytes for
+`f64`), it is cheap to copy, hence it implements the
 [`Copy`](https://doc.rust-lang.org/std/marker/trait.Copy.html) trait.
 
+# NaN policy
+
+The extremas of a bounding box are never NaN, as long as no transformation is
+applied with NaN arguments:
+- The constructors ([`new`](BoundingBox::new), [`try_new`](BoundingBox::try_new),
+  [`new_checked`](BoundingBox::new_checked), ...) and setters reject NaN values.
+- [`from_points`](BoundingBox::from_points) and
+  [`from_bounded_entities`](BoundingBox::from_bounded_entities) skip points /
+  boxes containing NaN, while their `_checked` variants return an error instead.
+
+Transformations such as [`translate`](BoundingBox::translate) or
+[`scale`](BoundingBox::scale) do not check their arguments for performance
+reasons. Calling them with NaN arguments (or with arguments resulting in
+undefined operations like `inf - inf`) may produce NaN extremas.
+
+The predicates give the following guarantees with respect to NaN:
+- [`covers_point`](BoundingBox::covers_point),
+  [`contains_point`](BoundingBox::contains_point) and `approx_covers_point` return
+  `false` if any coordinate of the point is NaN.
+- [`covers`](BoundingBox::covers), [`contains`](BoundingBox::contains),
+  [`intersects`](BoundingBox::intersects), [`overlaps`](BoundingBox::overlaps),
+  [`touches`](BoundingBox::touches) and their `approx_` variants return `false` if
+  any extremum of either box is NaN.
+
+```
+use bounding_box::BoundingBox;
+
+assert!(BoundingBox::try_new(f64::NAN, 1.0, 0.0, 1.0).is_none());
+
+let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+assert!(!bb.covers_point([f64::NAN, 0.5]));
+
+let mut nan_box = bb;
+nan_box.translate([f64::NAN, 0.0]);
+assert!(!bb.intersects(&nan_box));
+assert!(!nan_box.covers(&nan_box));
+```
+
+# Operators and iterator adaptors
+
+The following operators and iterator adaptors are available:
+
+| Expression              | Equivalent to                                   |
+|-------------------------|-------------------------------------------------|
+| `a \| b`                | [`a.union(&b)`](BoundingBox::union)             |
+| `a & b`                 | [`a.intersection(&b)`](BoundingBox::intersection) |
+| `a + [dx, dy]`          | [`a.translate([dx, dy])`](BoundingBox::translate) on a copy |
+| `a * factor`            | [`a.scale(factor)`](BoundingBox::scale) on a copy |
+| `a.extend(points)`      | enlarging `a` to cover all `points`             |
+| `boxes.sum()`           | [`BoundingBox::from_bounded_entities(boxes)`](BoundingBox::from_bounded_entities) |
+| `points.collect()`      | [`BoundingBox::from_points(points)`](BoundingBox::from_points) |
+
+Since a collection of points or boxes may be empty, [`Sum`](std::iter::Sum) is implemented
+for both `Option<BoundingBox>` and [`MaybeEmptyBox`], while [`FromIterator`]
+is implemented for [`MaybeEmptyBox`] only (Rust's orphan rules forbid
+implementing it for `Option<BoundingBox>` with an item type of `[f64; 2]`).
+
+```
+use bounding_box::{BoundingBox, MaybeEmptyBox};
+
+let a = BoundingBox::new(0.0, 2.0, 0.0, 2.0);
+let b = BoundingBox::new(1.0, 3.0, 1.0, 3.0);
+assert_eq!(a | b, BoundingBox::new(0.0, 3.0, 0.0, 3.0));
+assert_eq!(a & b, Some(BoundingBox::new(1.0, 2.0, 1.0, 2.0)));
+assert_eq!(a + [1.0, -1.0], BoundingBox::new(1.0, 3.0, -1.0, 1.0));
+assert_eq!(a * 2.0, BoundingBox::new(-1.0, 3.0, -1.0, 3.0));
+
+let points = vec![[1.0, 0.0], [0.0, 1.0], [-1.0, 0.5]];
+let bb: Option<BoundingBox> = points.iter().copied().collect::<MaybeEmptyBox>().into();
+assert_eq!(bb, Some(BoundingBox::new(-1.0, 1.0, 0.0, 1.0)));
+
+let mut bb = bb.unwrap();
+bb.extend([[2.0, 2.0], [0.0, -3.0]]);
+assert_eq!(bb, BoundingBox::new(-1.0, 2.0, -3.0, 2.0));
+
+let boxes = vec![a, b];
+let union: Option<BoundingBox> = boxes.iter().sum();
+assert_eq!(union, Some(a | b));
+```
+
 # Features
 
 This struct can be serialized / deserialized if the `serde` feature is enabled.
+Deserialization validates the extremas like [`try_new`](BoundingBox::try_new)
+does. Alternative representations (e.g. `[xmin, ymin, xmax, ymax]` arrays) are
+available in the `representation` module.
  */
 #[derive(Debug, Clone, Copy, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct BoundingBox {
-    xmin: f64,
-    xmax: f64,
-    ymin: f64,
-    ymax: f64,
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct BoundingBox<T = f64> {
+    xmin: T,
+    xmax: T,
+    ymin: T,
+    ymax: T,
 }
 
-impl BoundingBox {
+impl<T: Scalar> BoundingBox<T> {
     /**
     Generates a bounding box from minimum and maximum x- and y-values.
 
     # Panics
-    Panics if `xmin > xmax` or if `ymin > ymax`.
+    Panics if `xmin > xmax`, if `ymin > ymax` or if any of the values is NaN.
+    The panic message describes the violated condition, see
+    [`BoundingBoxError`]. Use [`BoundingBox::new_checked`] for a non-panicking
+    variant.
 
     # Examples
 
@@ -66,28 +223,62 @@ impl BoundingBox {
     let _ = BoundingBox::new(2.0, 1.0, 0.0, 1.0);
     ```
      */
-    pub fn new(xmin: f64, xmax: f64, ymin: f64, ymax: f64) -> Self {
-        return Self::try_new(xmin, xmax, ymin, ymax)
-            .expect("one of the conditions xmin <= xmax and ymin <= ymax is not fulfilled");
+    pub fn new(xmin: T, xmax: T, ymin: T, ymax: T) -> Self {
+        match Self::new_checked(xmin, xmax, ymin, ymax) {
+            Ok(bb) => return bb,
+            Err(err) => panic!("{err}"),
+        }
     }
 
     /**
-    Like [`BoundingBox::new`], but returns `None` instead of panicking if `xmin > xmax` or if `ymin > ymax`.
+    Like [`BoundingBox::new`], but returns a [`BoundingBoxError`] describing
+    the violated condition instead of panicking.
+
+    The values are checked in the following order: First, all values are
+    checked for NaN (in the order `xmin`, `xmax`, `ymin`, `ymax`), then
+    `xmin <= xmax` and finally `ymin <= ymax`.
 
     # Examples
 
     ```
-    use bounding_box::BoundingBox;
-
-    assert!(BoundingBox::try_new(0.0, 1.0, 0.0, 1.0).is_some());
-    assert!(BoundingBox::try_new(2.0, 1.0, 0.0, 1.0).is_none());
+    use bounding_box::{BoundingBox, BoundingBoxError, Extremum};
+
+    assert!(BoundingBox::new_checked(0.0, 1.0, 0.0, 1.0).is_ok());
+    assert_eq!(
+        BoundingBox::new_checked(0, 1, 2, 1),
+        Err(BoundingBoxError::InvertedY { ymin: 2.0, ymax: 1.0 })
+    );
+    assert_eq!(
+        BoundingBox::new_checked(0.0, f64::NAN, 0.0, 1.0),
+        Err(BoundingBoxError::NaN(Extremum::Xmax))
+    );
     ```
      */
-    pub fn try_new(xmin: f64, xmax: f64, ymin: f64, ymax: f64) -> Option<Self> {
-        if xmin > xmax || ymin > ymax {
-            return None;
+    pub fn new_checked(xmin: T, xmax: T, ymin: T, ymax: T) -> Result<Self, BoundingBoxError> {
+        let extremas = [
+            (Extremum::Xmin, xmin),
+            (Extremum::Xmax, xmax),
+            (Extremum::Ymin, ymin),
+            (Extremum::Ymax, ymax),
+        ];
+        for (extremum, val) in extremas {
+            if val.is_nan() {
+                return Err(BoundingBoxError::NaN(extremum));
+            }
         }
-        return Some(BoundingBox {
+        if xmin > xmax {
+            return Err(BoundingBoxError::InvertedX {
+                xmin: xmin.to_f64(),
+                xmax: xmax.to_f64(),
+            });
+        }
+        if ymin > ymax {
+            return Err(BoundingBoxError::InvertedY {
+                ymin: ymin.to_f64(),
+                ymax: ymax.to_f64(),
+            });
+        }
+        return Ok(BoundingBox {
             xmin,
             xmax,
             ymin,
@@ -95,37 +286,94 @@ impl BoundingBox {
         });
     }
 
+    /**
+    Like [`BoundingBox::new_checked`], but additionally returns
+    [`BoundingBoxError::Infinite`] if any of the values is infinite.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, BoundingBoxError, Extremum};
+
+    assert!(BoundingBox::new_finite_checked(0.0, 1.0, 0.0, 1.0).is_ok());
+    assert_eq!(
+        BoundingBox::new_finite_checked(0.0, 1.0, 0.0, f64::INFINITY),
+        Err(BoundingBoxError::Infinite(Extremum::Ymax))
+    );
+
+    // Infinite values are allowed by the other constructors
+    assert!(BoundingBox::new_checked(0.0, 1.0, 0.0, f64::INFINITY).is_ok());
+    ```
+     */
+    pub fn new_finite_checked(
+        xmin: T,
+        xmax: T,
+        ymin: T,
+        ymax: T,
+    ) -> Result<Self, BoundingBoxError> {
+        let bb = Self::new_checked(xmin, xmax, ymin, ymax)?;
+        let extremas = [
+            (Extremum::Xmin, xmin),
+            (Extremum::Xmax, xmax),
+            (Extremum::Ymin, ymin),
+            (Extremum::Ymax, ymax),
+        ];
+        for (extremum, val) in extremas {
+            if !val.is_finite() {
+                return Err(BoundingBoxError::Infinite(extremum));
+            }
+        }
+        return Ok(bb);
+    }
+
+    /**
+    Like [`BoundingBox::new`], but returns `None` instead of panicking if `xmin > xmax`, if `ymin > ymax` or if
+    any of the values is NaN. Use [`BoundingBox::new_checked`] to find out which condition was violated.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    assert!(BoundingBox::try_new(0.0, 1.0, 0.0, 1.0).is_some());
+    assert!(BoundingBox::try_new(2.0, 1.0, 0.0, 1.0).is_none());
+    ```
+     */
+    pub fn try_new(xmin: T, xmax: T, ymin: T, ymax: T) -> Option<Self> {
+        return Self::new_checked(xmin, xmax, ymin, ymax).ok();
+    }
+
     /**
     Returns the minimum x-value of the bounding box.
      */
-    pub fn xmin(&self) -> f64 {
+    pub fn xmin(&self) -> T {
         return self.xmin;
     }
 
     /**
     Returns the maximum x-value of the bounding box.
      */
-    pub fn xmax(&self) -> f64 {
+    pub fn xmax(&self) -> T {
         return self.xmax;
     }
 
     /**
     Returns the minimum y-value of the bounding box.
      */
-    pub fn ymin(&self) -> f64 {
+    pub fn ymin(&self) -> T {
         return self.ymin;
     }
 
     /**
     Returns the maximum y-value of the bounding box.
      */
-    pub fn ymax(&self) -> f64 {
+    pub fn ymax(&self) -> T {
         return self.ymax;
     }
 
     /**
-    Fallible sets a new value for `xmin`. If the new value is bigger than `xmax`, the old value is left unchanged
-    and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
+    Fallible sets a new value for `xmin`. If the new value is bigger than `xmax` or NaN, the old value is left
+    unchanged and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
 
     # Examples
 
@@ -142,18 +390,13 @@ impl BoundingBox {
     assert_eq!(bb.xmin(), 0.5);
     ```
      */
-    pub fn try_set_xmin(&mut self, val: f64) -> bool {
-        if val > self.xmax {
-            return false;
-        } else {
-            self.xmin = val;
-            return true;
-        }
+    pub fn try_set_xmin(&mut self, val: T) -> bool {
+        return self.set_xmin_checked(val).is_ok();
     }
 
     /**
-    Fallible sets a new value for `xmax`. If the new value is smaller than `xmin`, the old value is left unchanged
-    and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
+    Fallible sets a new value for `xmax`. If the new value is smaller than `xmin` or NaN, the old value is left
+    unchanged and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
 
     # Examples
 
@@ -170,18 +413,13 @@ impl BoundingBox {
     assert_eq!(bb.xmax(), 0.5);
     ```
      */
-    pub fn try_set_xmax(&mut self, val: f64) -> bool {
-        if val < self.xmin {
-            return false;
-        } else {
-            self.xmax = val;
-            return true;
-        }
+    pub fn try_set_xmax(&mut self, val: T) -> bool {
+        return self.set_xmax_checked(val).is_ok();
     }
 
     /**
-    Fallible sets a new value for `ymin`. If the new value is bigger than `ymax`, the old value is left unchanged
-    and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
+    Fallible sets a new value for `ymin`. If the new value is bigger than `ymax` or NaN, the old value is left
+    unchanged and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
 
     # Examples
 
@@ -198,18 +436,13 @@ impl BoundingBox {
     assert_eq!(bb.ymin(), 0.5);
     ```
      */
-    pub fn try_set_ymin(&mut self, val: f64) -> bool {
-        if val > self.ymax {
-            return false;
-        } else {
-            self.ymin = val;
-            return true;
-        }
+    pub fn try_set_ymin(&mut self, val: T) -> bool {
+        return self.set_ymin_checked(val).is_ok();
     }
 
     /**
-    Fallible sets a new value for `ymax`. If the new value is smaller than `ymin`, the old value is left unchanged
-    and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
+    Fallible sets a new value for `ymax`. If the new value is smaller than `ymin` or NaN, the old value is left
+    unchanged and this function returns `false`. Otherwise, it returns `true` and the old value is replaced by the new value.
 
     # Examples
 
@@ -226,19 +459,170 @@ impl BoundingBox {
     assert_eq!(bb.ymax(), 0.5);
     ```
      */
-    pub fn try_set_ymax(&mut self, val: f64) -> bool {
+    pub fn try_set_ymax(&mut self, val: T) -> bool {
+        return self.set_ymax_checked(val).is_ok();
+    }
+
+    /**
+    Like [`BoundingBox::try_set_xmin`], but returns a [`BoundingBoxError`]
+    describing the violated condition instead of `false`. In case of an error,
+    the old value is left unchanged.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, BoundingBoxError, Extremum};
+
+    let mut bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.set_xmin_checked(0.5).is_ok());
+    assert_eq!(bb.xmin(), 0.5);
+
+    let mut bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    assert_eq!(
+        bb.set_xmin_checked(1.5),
+        Err(BoundingBoxError::InvertedX { xmin: 1.5, xmax: 1.0 })
+    );
+    assert_eq!(bb.set_xmin_checked(f64::NAN), Err(BoundingBoxError::NaN(Extremum::Xmin)));
+    assert_eq!(bb, BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    ```
+     */
+    pub fn set_xmin_checked(&mut self, val: T) -> Result<(), BoundingBoxError> {
+        if val.is_nan() {
+            return Err(BoundingBoxError::NaN(Extremum::Xmin));
+        }
+        if val > self.xmax {
+            return Err(BoundingBoxError::InvertedX {
+                xmin: val.to_f64(),
+                xmax: self.xmax.to_f64(),
+            });
+        }
+        self.xmin = val;
+        return Ok(());
+    }
+
+    /**
+    Like [`BoundingBox::try_set_xmax`], but returns a [`BoundingBoxError`]
+    describing the violated condition instead of `false`. In case of an error,
+    the old value is left unchanged.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, BoundingBoxError, Extremum};
+
+    let mut bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.set_xmax_checked(0.5).is_ok());
+    assert_eq!(bb.xmax(), 0.5);
+
+    let mut bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    assert_eq!(
+        bb.set_xmax_checked(-0.5),
+        Err(BoundingBoxError::InvertedX { xmin: 0.0, xmax: -0.5 })
+    );
+    assert_eq!(bb.set_xmax_checked(f64::NAN), Err(BoundingBoxError::NaN(Extremum::Xmax)));
+    assert_eq!(bb, BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    ```
+     */
+    pub fn set_xmax_checked(&mut self, val: T) -> Result<(), BoundingBoxError> {
+        if val.is_nan() {
+            return Err(BoundingBoxError::NaN(Extremum::Xmax));
+        }
+        if val < self.xmin {
+            return Err(BoundingBoxError::InvertedX {
+                xmin: self.xmin.to_f64(),
+                xmax: val.to_f64(),
+            });
+        }
+        self.xmax = val;
+        return Ok(());
+    }
+
+    /**
+    Like [`BoundingBox::try_set_ymin`], but returns a [`BoundingBoxError`]
+    describing the violated condition instead of `false`. In case of an error,
+    the old value is left unchanged.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, BoundingBoxError, Extremum};
+
+    let mut bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.set_ymin_checked(0.5).is_ok());
+    assert_eq!(bb.ymin(), 0.5);
+
+    let mut bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    assert_eq!(
+        bb.set_ymin_checked(1.5),
+        Err(BoundingBoxError::InvertedY { ymin: 1.5, ymax: 1.0 })
+    );
+    assert_eq!(bb.set_ymin_checked(f64::NAN), Err(BoundingBoxError::NaN(Extremum::Ymin)));
+    assert_eq!(bb, BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    ```
+     */
+    pub fn set_ymin_checked(&mut self, val: T) -> Result<(), BoundingBoxError> {
+        if val.is_nan() {
+            return Err(BoundingBoxError::NaN(Extremum::Ymin));
+        }
+        if val > self.ymax {
+            return Err(BoundingBoxError::InvertedY {
+                ymin: val.to_f64(),
+                ymax: self.ymax.to_f64(),
+            });
+        }
+        self.ymin = val;
+        return Ok(());
+    }
+
+    /**
+    Like [`BoundingBox::try_set_ymax`], but returns a [`BoundingBoxError`]
+    describing the violated condition instead of `false`. In case of an error,
+    the old value is left unchanged.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, BoundingBoxError, Extremum};
+
+    let mut bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.set_ymax_checked(0.5).is_ok());
+    assert_eq!(bb.ymax(), 0.5);
+
+    let mut bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    assert_eq!(
+        bb.set_ymax_checked(-0.5),
+        Err(BoundingBoxError::InvertedY { ymin: 0.0, ymax: -0.5 })
+    );
+    assert_eq!(bb.set_ymax_checked(f64::NAN), Err(BoundingBoxError::NaN(Extremum::Ymax)));
+    assert_eq!(bb, BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    ```
+     */
+    pub fn set_ymax_checked(&mut self, val: T) -> Result<(), BoundingBoxError> {
+        if val.is_nan() {
+            return Err(BoundingBoxError::NaN(Extremum::Ymax));
+        }
         if val < self.ymin {
-            return false;
-        } else {
-            self.ymax = val;
-            return true;
+            return Err(BoundingBoxError::InvertedY {
+                ymin: self.ymin.to_f64(),
+                ymax: val.to_f64(),
+            });
         }
+        self.ymax = val;
+        return Ok(());
     }
 
     /**
     Creates a bounding box from an iterator over vertices.
 
-    If the iterator is empty, this function returns `None`.
+    Points with a NaN coordinate are skipped. If the iterator is empty or only
+    yields such points, this function returns `None`. Use
+    [`BoundingBox::from_points_checked`] to treat NaN coordinates as an error
+    instead.
+
     ```
     use bounding_box::BoundingBox;
 
@@ -256,42 +640,106 @@ impl BoundingBox {
      assert_eq!(bb.xmax(), 7.0);
      assert_eq!(bb.ymin(), -12.3);
      assert_eq!(bb.ymax(), 11.0);
+
+     // Points with NaN coordinates are skipped
+     let verts = vec![[f64::NAN, 100.0], [1.0, 0.0], [2.0, f64::NAN], [0.0, 1.0]];
+     let bb = BoundingBox::from_points(verts.into_iter()).unwrap();
+     assert_eq!(bb, BoundingBox::new(0.0, 1.0, 0.0, 1.0));
      ```
      */
-    pub fn from_points<'a, T: Into<[f64; 2]>, I: Iterator<Item = T>>(mut verts: I) -> Option<Self> {
-        match verts.next() {
-            Some(pt) => {
-                let pt: [f64; 2] = pt.into();
-                let mut xmin = pt[0];
-                let mut xmax = pt[0];
-                let mut ymin = pt[1];
-                let mut ymax = pt[1];
-                for vert in verts {
-                    let pt: [f64; 2] = vert.into();
-                    if pt[0] > xmax {
-                        xmax = pt[0]
-                    }
-                    if pt[0] < xmin {
-                        xmin = pt[0]
-                    }
-                    if pt[1] > ymax {
-                        ymax = pt[1]
-                    }
-                    if pt[1] < ymin {
-                        ymin = pt[1]
-                    }
-                }
-                return Some(BoundingBox::new(xmin, xmax, ymin, ymax));
+    pub fn from_points<P: Into<[T; 2]>, I: Iterator<Item = P>>(verts: I) -> Option<Self> {
+        let mut bb: Option<Self> = None;
+        for vert in verts {
+            let pt: [T; 2] = vert.into();
+            if pt[0].is_nan() || pt[1].is_nan() {
+                continue;
+            }
+            match bb.as_mut() {
+                Some(bb) => bb.include_point(pt),
+                None => bb = Some(BoundingBox::new(pt[0], pt[0], pt[1], pt[1])),
+            }
+        }
+        return bb;
+    }
+
+    /**
+    Like [`BoundingBox::from_points`], but returns
+    [`BoundingBoxError::NaNInput`] with the index of the first point which has
+    a NaN coordinate instead of skipping it.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, BoundingBoxError};
+
+    let verts = vec![[1.0, 0.0], [0.0, 1.0]];
+    let bb = BoundingBox::from_points_checked(verts.into_iter()).unwrap();
+    assert_eq!(bb, Some(BoundingBox::new(0.0, 1.0, 0.0, 1.0)));
+
+    let verts = vec![[1.0, 0.0], [0.0, 1.0], [f64::NAN, 1.0]];
+    assert_eq!(
+        BoundingBox::from_points_checked(verts.into_iter()),
+        Err(BoundingBoxError::NaNInput { index: 2 })
+    );
+
+    // An empty iterator is not an error
+    let verts: Vec<[f64; 2]> = Vec::new();
+    assert_eq!(BoundingBox::from_points_checked(verts.into_iter()), Ok(None));
+    ```
+     */
+    pub fn from_points_checked<P: Into<[T; 2]>, I: Iterator<Item = P>>(
+        verts: I,
+    ) -> Result<Option<Self>, BoundingBoxError> {
+        let mut bb: Option<Self> = None;
+        for (index, vert) in verts.enumerate() {
+            let pt: [T; 2] = vert.into();
+            if pt[0].is_nan() || pt[1].is_nan() {
+                return Err(BoundingBoxError::NaNInput { index });
             }
-            None => return None,
+            match bb.as_mut() {
+                Some(bb) => bb.include_point(pt),
+                None => bb = Some(BoundingBox::new(pt[0], pt[0], pt[1], pt[1])),
+            }
+        }
+        return Ok(bb);
+    }
+
+    /// Enlarges `self` so that it covers `pt`.
+    fn include_point(&mut self, pt: [T; 2]) {
+        if pt[0] > self.xmax {
+            self.xmax = pt[0]
+        }
+        if pt[0] < self.xmin {
+            self.xmin = pt[0]
+        }
+        if pt[1] > self.ymax {
+            self.ymax = pt[1]
         }
+        if pt[1] < self.ymin {
+            self.ymin = pt[1]
+        }
+    }
+
+    /// Returns true if any of the extremas is NaN. This can only happen if a
+    /// transformation (e.g. [`BoundingBox::translate`]) is applied with NaN
+    /// arguments or results in an undefined operation such as `inf - inf`.
+    fn has_nan(&self) -> bool {
+        return self.xmin.is_nan()
+            || self.xmax.is_nan()
+            || self.ymin.is_nan()
+            || self.ymax.is_nan();
     }
 
     /**
     Creates a bounding box from an iterator over any types implementing
     [`Into<BoundingBox>`].
 
-    If the iterator is empty, this function returns `None`.
+    Bounding boxes with NaN extremas (which can only result from
+    transformations with NaN arguments, see the
+    [NaN policy](BoundingBox#nan-policy)) are skipped. If the iterator is empty
+    or only yields such boxes, this function returns `None`. Use
+    [`BoundingBox::from_bounded_entities_checked`] to treat NaN extremas as an
+    error instead.
 
     ```
     use bounding_box::BoundingBox;
@@ -321,12 +769,60 @@ impl BoundingBox {
     assert_eq!(bb.ymax(), 4.0);
      ```
      */
-    pub fn from_bounded_entities<T: Into<BoundingBox>, I: Iterator<Item = T>>(
-        mut entities: I,
+    pub fn from_bounded_entities<E: Into<BoundingBox<T>>, I: Iterator<Item = E>>(
+        entities: I,
     ) -> Option<Self> {
-        let first_bb: BoundingBox = entities.next()?.into();
-        let bb = entities.fold(first_bb, |acc, drawable| drawable.into().union(&acc));
-        return Some(bb);
+        return entities
+            .map(Into::into)
+            .filter(|bb: &BoundingBox<T>| !bb.has_nan())
+            .reduce(|acc, bb| bb.union(&acc));
+    }
+
+    /**
+    Like [`BoundingBox::from_bounded_entities`], but returns
+    [`BoundingBoxError::NaNInput`] with the index of the first entity whose
+    bounding box has a NaN extremum instead of skipping it.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, BoundingBoxError};
+
+    let boxes = [BoundingBox::new(0.0, 1.0, 0.0, 1.0), BoundingBox::new(2.0, 3.0, -1.0, 0.0)];
+    assert_eq!(
+        BoundingBox::from_bounded_entities_checked(boxes.iter()),
+        Ok(Some(BoundingBox::new(0.0, 3.0, -1.0, 1.0)))
+    );
+
+    // Translating by NaN results in NaN extremas
+    let mut nan_box = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    nan_box.translate([f64::NAN, 0.0]);
+    let boxes = [BoundingBox::new(0.0, 1.0, 0.0, 1.0), nan_box];
+    assert_eq!(
+        BoundingBox::from_bounded_entities_checked(boxes.iter()),
+        Err(BoundingBoxError::NaNInput { index: 1 })
+    );
+    assert_eq!(
+        BoundingBox::from_bounded_entities(boxes.iter()),
+        Some(BoundingBox::new(0.0, 1.0, 0.0, 1.0))
+    );
+    ```
+     */
+    pub fn from_bounded_entities_checked<E: Into<BoundingBox<T>>, I: Iterator<Item = E>>(
+        entities: I,
+    ) -> Result<Option<Self>, BoundingBoxError> {
+        let mut union: Option<Self> = None;
+        for (index, entity) in entities.enumerate() {
+            let bb: BoundingBox<T> = entity.into();
+            if bb.has_nan() {
+                return Err(BoundingBoxError::NaNInput { index });
+            }
+            union = Some(match union {
+                Some(union) => bb.union(&union),
+                None => bb,
+            });
+        }
+        return Ok(union);
     }
 
     /**
@@ -349,31 +845,27 @@ impl BoundingBox {
     assert_eq!(bb.ymax(), 5.0);
     ```
     */
-    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
-        let xmin: f64;
-        let xmax: f64;
-        let ymin: f64;
-        let ymax: f64;
-        if self.xmin > other.xmin {
-            xmin = other.xmin;
+    pub fn union(&self, other: &BoundingBox<T>) -> BoundingBox<T> {
+        let xmin = if self.xmin > other.xmin {
+            other.xmin
         } else {
-            xmin = self.xmin;
-        }
-        if self.xmax > other.xmax {
-            xmax = self.xmax;
+            self.xmin
+        };
+        let xmax = if self.xmax > other.xmax {
+            self.xmax
         } else {
-            xmax = other.xmax;
-        }
-        if self.ymin > other.ymin {
-            ymin = other.ymin;
+            other.xmax
+        };
+        let ymin = if self.ymin > other.ymin {
+            other.ymin
         } else {
-            ymin = self.ymin;
-        }
-        if self.ymax > other.ymax {
-            ymax = self.ymax;
+            self.ymin
+        };
+        let ymax = if self.ymax > other.ymax {
+            self.ymax
         } else {
-            ymax = other.ymax;
-        }
+            other.ymax
+        };
         return BoundingBox {
             xmin,
             xmax,
@@ -383,87 +875,259 @@ impl BoundingBox {
     }
 
     /**
-    Returns true if `self` covers a given point.
+    Creates the intersection of two bounding boxes.
 
-    A point is "covered" by the bounding box if it is either within or on the
-    boundaries of `self`. Mathematically speaking, the following two
-    inequalities must be true:
+    The intersection of two bounding boxes is the region covered by both
+    bounding boxes. If the boxes do not [intersect](BoundingBox::intersects),
+    this function returns `None`. If the boxes are just
+    [touching](BoundingBox::touches), the returned box is degenerate, i.e. its
+    width and / or height is zero.
 
-    `self.xmin() <= point[0] <= self.xmax()`
+    # Examples
 
-    `self.ymin() <= point[1] <= self.ymax()`
+    ```
+    use bounding_box::BoundingBox;
 
-    If the boundaries should be excluded, use [`BoundingBox::contains_point`]
-    instead.
+    let bb1 = BoundingBox::new(-1.0, 3.5, 2.0, 3.0);
+    let bb2 = BoundingBox::new(-5.0, 2.5, -1.0, 2.5);
+    assert_eq!(bb1.intersection(&bb2), Some(BoundingBox::new(-1.0, 2.5, 2.0, 2.5)));
 
-    If the feature flag `approx` is enabled, the method
-    [`BoundingBox::approx_covers_point`] is made available, which allows
-    providing tolerances for the aforementioned inequalities.
+    // Touching boxes => degenerate intersection
+    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox::new(1.0, 2.0, 0.5, 2.0);
+    let bb = bb1.intersection(&bb2).expect("boxes are touching");
+    assert_eq!(bb, BoundingBox::new(1.0, 1.0, 0.5, 1.0));
+    assert_eq!(bb.width(), 0.0);
+
+    // Disjoint boxes
+    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox::new(2.0, 3.0, 0.0, 1.0);
+    assert!(bb1.intersection(&bb2).is_none());
+    ```
+    */
+    pub fn intersection(&self, other: &BoundingBox<T>) -> Option<BoundingBox<T>> {
+        if !self.intersects(other) {
+            return None;
+        }
+        let xmin = if self.xmin > other.xmin {
+            self.xmin
+        } else {
+            other.xmin
+        };
+        let xmax = if self.xmax > other.xmax {
+            other.xmax
+        } else {
+            self.xmax
+        };
+        let ymin = if self.ymin > other.ymin {
+            self.ymin
+        } else {
+            other.ymin
+        };
+        let ymax = if self.ymax > other.ymax {
+            other.ymax
+        } else {
+            self.ymax
+        };
+        return Some(BoundingBox {
+            xmin,
+            xmax,
+            ymin,
+            ymax,
+        });
+    }
+
+    /**
+    Fallible clips `self` to `other`. If the boxes
+    [intersect](BoundingBox::intersects), `self` is replaced by the
+    [intersection](BoundingBox::intersection) and this function returns `true`.
+    Otherwise, `self` is left unchanged and this function returns `false`.
 
     # Examples
+
     ```
     use bounding_box::BoundingBox;
 
-    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    let mut bb = BoundingBox::new(0.0, 2.0, 0.0, 2.0);
 
-    assert!(bb.covers_point([0.5, 0.5]));
-    assert!(bb.covers_point([0.0, 0.0])); // On boundary
-    assert!(!bb.covers_point([-1.0, 0.0]));
-    assert!(!bb.covers_point([0.0, 2.0]));
-     */
-    pub fn covers_point<T: Into<[f64; 2]>>(&self, point: T) -> bool {
-        let point: [f64; 2] = point.into();
-        return self.xmin <= point[0]
-            && self.ymin <= point[1]
-            && self.xmax >= point[0]
-            && self.ymax >= point[1];
+    assert!(bb.clip_to(&BoundingBox::new(1.0, 3.0, -1.0, 1.5)));
+    assert_eq!(bb, BoundingBox::new(1.0, 2.0, 0.0, 1.5));
+
+    assert!(!bb.clip_to(&BoundingBox::new(5.0, 6.0, 0.0, 1.0)));
+    assert_eq!(bb, BoundingBox::new(1.0, 2.0, 0.0, 1.5));
+    ```
+    */
+    pub fn clip_to(&mut self, other: &BoundingBox<T>) -> bool {
+        match self.intersection(other) {
+            Some(bb) => {
+                *self = bb;
+                return true;
+            }
+            None => return false,
+        }
     }
 
     /**
-    Like [`BoundingBox::covers_point`], but with absolute and ULPs tolerances.
+    Creates the common intersection of an iterator over any types implementing
+    [`Into<BoundingBox>`].
 
-    This variant of [`BoundingBox::covers_point`] allows specifying an absolute
-    and an [ULP](https://en.wikipedia.org/wiki/Unit_in_the_last_place)
-    tolerance. These tolerances  are used to check if the given point lies
-    "approximately" on an edge of the bounding box.
+    This is the intersection counterpart of
+    [`BoundingBox::from_bounded_entities`]. If the iterator is empty or if
+    there is no region covered by all bounding boxes, this function returns
+    `None`.
 
     # Examples
+
     ```
     use bounding_box::BoundingBox;
 
-    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    let boxes = [
+        BoundingBox::new(0.0, 4.0, 0.0, 4.0),
+        BoundingBox::new(1.0, 5.0, -1.0, 3.0),
+        BoundingBox::new(-1.0, 3.0, 2.0, 6.0),
+    ];
+    let bb = BoundingBox::from_intersecting_entities(boxes.into_iter()).expect("boxes have a common intersection");
+    assert_eq!(bb, BoundingBox::new(1.0, 3.0, 2.0, 3.0));
 
-    // Exact check: Point is outside the bounding box
-    assert!(!bb.covers_point([1.0001, 1.0]));
+    let boxes = [
+        BoundingBox::new(0.0, 1.0, 0.0, 1.0),
+        BoundingBox::new(0.5, 2.0, 0.5, 2.0),
+        BoundingBox::new(1.5, 2.0, 0.0, 1.0),
+    ];
+    assert!(BoundingBox::from_intersecting_entities(boxes.into_iter()).is_none());
+    ```
+    */
+    pub fn from_intersecting_entities<E: Into<BoundingBox<T>>, I: Iterator<Item = E>>(
+        mut entities: I,
+    ) -> Option<Self> {
+        let first_bb: BoundingBox<T> = entities.next()?.into();
+        return entities.try_fold(first_bb, |acc, entity| entity.into().intersection(&acc));
+    }
 
-    // Check using tolerances: Point is inside bounding box
-    assert!(bb.approx_covers_point([1.0001, 1.0], 1e-3, 0));
+    /**
+    Returns the difference `self - other` as an iterator over up to four
+    non-overlapping bounding boxes.
+
+    The returned boxes cover exactly the area of `self` which is not covered by
+    `other`. They do not [overlap](BoundingBox::overlaps) each other or
+    `other`, but they may [touch](BoundingBox::touches). The following cases
+    are distinguished:
+    - If `self` and `other` do not [overlap](BoundingBox::overlaps), `self` is
+      returned unchanged.
+    - If `other` [covers](BoundingBox::covers) `self`, nothing is returned.
+    - Otherwise, `self` is split into a left and a right strip spanning the full
+      height of `self` and a bottom and top strip in between them. Empty strips
+      are omitted.
+
+    The returned iterator does not allocate.
 
-    // Check using a finer tolerance: Point is outside the bounding box
-    assert!(!bb.approx_covers_point([1.0001, 1.0], 1e-6, 0));
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 3.0, 0.0, 3.0);
+
+    // Hole in the middle => four strips
+    let hole = BoundingBox::new(1.0, 2.0, 1.0, 2.0);
+    let pieces: Vec<_> = bb.difference(&hole).collect();
+    assert_eq!(pieces, vec![
+        BoundingBox::new(0.0, 1.0, 0.0, 3.0),
+        BoundingBox::new(2.0, 3.0, 0.0, 3.0),
+        BoundingBox::new(1.0, 2.0, 0.0, 1.0),
+        BoundingBox::new(1.0, 2.0, 2.0, 3.0),
+    ]);
+
+    // Cut off the right side
+    let cut = BoundingBox::new(2.0, 4.0, -1.0, 4.0);
+    let pieces: Vec<_> = bb.difference(&cut).collect();
+    assert_eq!(pieces, vec![BoundingBox::new(0.0, 2.0, 0.0, 3.0)]);
+
+    // Fully covered
+    assert_eq!(bb.difference(&BoundingBox::new(-1.0, 4.0, -1.0, 4.0)).count(), 0);
+
+    // Only touching => unchanged
+    let pieces: Vec<_> = bb.difference(&BoundingBox::new(3.0, 4.0, 0.0, 3.0)).collect();
+    assert_eq!(pieces, vec![bb]);
     ```
+    */
+    pub fn difference(&self, other: &BoundingBox<T>) -> Difference<T> {
+        return Difference::new(self, other);
+    }
 
-    # Features
+    /**
+    Decomposes an iterator over any types implementing [`Into<BoundingBox>`]
+    into non-overlapping bounding boxes covering the same area.
 
-    This function uses the [`ulps_eq`] macro of the [approx] crate, therefore
-    the `approx` feature needs to be enabled.
+    The boxes returned by the iterator do not [overlap](BoundingBox::overlaps)
+    each other (they may [touch](BoundingBox::touches)) and their union covers
+    exactly the same area as the input boxes. They are computed lazily from
+    repeated [differences](BoundingBox::difference): each input box is reduced
+    by all boxes preceding it in the input.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let boxes = [
+        BoundingBox::new(0.0, 2.0, 0.0, 2.0),
+        BoundingBox::new(1.0, 3.0, 1.0, 3.0),
+        BoundingBox::new(0.5, 1.5, 0.5, 1.5), // Fully covered by the first box
+    ];
+
+    let cover: Vec<_> = BoundingBox::disjoint_cover(boxes.into_iter()).collect();
+    assert_eq!(cover.len(), 3);
+    for (i, a) in cover.iter().enumerate() {
+        for b in cover[(i + 1)..].iter() {
+            assert!(!a.overlaps(b));
+        }
+    }
+    let area: f64 = cover.iter().map(|bb| bb.width() * bb.height()).sum();
+    assert_eq!(area, 7.0);
+    ```
+    */
+    pub fn disjoint_cover<E: Into<BoundingBox<T>>, I: Iterator<Item = E>>(
+        entities: I,
+    ) -> DisjointCover<T> {
+        return DisjointCover::new(entities.map(Into::into).collect());
+    }
+
+    /**
+    Returns true if `self` covers a given point.
+
+    A point is "covered" by the bounding box if it is either within or on the
+    boundaries of `self`. Mathematically speaking, the following two
+    inequalities must be true:
+
+    `self.xmin() <= point[0] <= self.xmax()`
+
+    `self.ymin() <= point[1] <= self.ymax()`
+
+    If the boundaries should be excluded, use [`BoundingBox::contains_point`]
+    instead.
+
+    If the feature flag `approx` is enabled, the method
+    [`BoundingBox::approx_covers_point`] is made available, which allows
+    providing tolerances for the aforementioned inequalities.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+
+    assert!(bb.covers_point([0.5, 0.5]));
+    assert!(bb.covers_point([0.0, 0.0])); // On boundary
+    assert!(!bb.covers_point([-1.0, 0.0]));
+    assert!(!bb.covers_point([0.0, 2.0]));
      */
-    #[cfg(feature = "approx")]
-    pub fn approx_covers_point<T: Into<[f64; 2]>>(
-        &self,
-        point: T,
-        epsilon: f64,
-        max_ulps: u32,
-    ) -> bool {
-        let point: [f64; 2] = point.into();
-        return (self.xmin < point[0]
-            || ulps_eq!(self.xmin, point[0], epsilon = epsilon, max_ulps = max_ulps))
-            && (self.ymin < point[1]
-                || ulps_eq!(self.ymin, point[1], epsilon = epsilon, max_ulps = max_ulps))
-            && (self.xmax > point[0]
-                || ulps_eq!(self.xmax, point[0], epsilon = epsilon, max_ulps = max_ulps))
-            && (self.ymax > point[1]
-                || ulps_eq!(self.ymax, point[1], epsilon = epsilon, max_ulps = max_ulps));
+    pub fn covers_point<P: Into<[T; 2]>>(&self, point: P) -> bool {
+        let point: [T; 2] = point.into();
+        return self.xmin <= point[0]
+            && self.ymin <= point[1]
+            && self.xmax >= point[0]
+            && self.ymax >= point[1];
     }
 
     /**
@@ -490,8 +1154,8 @@ impl BoundingBox {
     assert!(!bb.contains_point([-1.0, 0.0]));
     assert!(!bb.contains_point([0.0, 2.0]));
      */
-    pub fn contains_point<T: Into<[f64; 2]>>(&self, point: T) -> bool {
-        let point: [f64; 2] = point.into();
+    pub fn contains_point<P: Into<[T; 2]>>(&self, point: P) -> bool {
+        let point: [T; 2] = point.into();
         return self.xmin < point[0]
             && self.ymin < point[1]
             && self.xmax > point[0]
@@ -548,63 +1212,6 @@ impl BoundingBox {
             && self.ymax >= other.ymax;
     }
 
-    /**
-    Like [`BoundingBox::covers`], but with absolute and ULPs tolerances.
-
-    This variant of [`BoundingBox::covers`] allows specifying an absolute and
-    an [ULP](https://en.wikipedia.org/wiki/Unit_in_the_last_place) tolerance.
-    These tolerances are used to check if the extremas of the boxes are
-    "approximately" equal.
-
-    ```
-    use bounding_box::BoundingBox;
-
-    // bb1 covers bb2 depending on the selected tolerances
-    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
-    let bb2 = BoundingBox::new(0.0, 1.0001, 0.0, 0.5);
-
-    assert!(!bb1.covers(&bb2));
-    assert!(bb1.approx_covers(&bb2, 1e-3, 0));
-    assert!(!bb1.approx_covers(&bb2, 1e-6, 0));
-    ```
-
-    # Features
-
-    This function uses the [`ulps_eq`] macro of the [approx] crate, therefore
-    the `approx` feature needs to be enabled.
-    */
-    #[cfg(feature = "approx")]
-    pub fn approx_covers(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
-        return (self.xmin < other.xmin
-            || ulps_eq!(
-                self.xmin,
-                other.xmin,
-                epsilon = epsilon,
-                max_ulps = max_ulps
-            ))
-            && (self.ymin < other.ymin
-                || ulps_eq!(
-                    self.ymin,
-                    other.ymin,
-                    epsilon = epsilon,
-                    max_ulps = max_ulps
-                ))
-            && (self.xmax > other.xmax
-                || ulps_eq!(
-                    self.xmax,
-                    other.xmax,
-                    epsilon = epsilon,
-                    max_ulps = max_ulps
-                ))
-            && (self.ymax > other.ymax
-                || ulps_eq!(
-                    self.ymax,
-                    other.ymax,
-                    epsilon = epsilon,
-                    max_ulps = max_ulps
-                ));
-    }
-
     /**
     Returns true if `self` contains `other`.
 
@@ -656,53 +1263,6 @@ impl BoundingBox {
             && self.ymax > other.ymax;
     }
 
-    /**
-    Check if the two bounding boxes are approximately equal.
-
-    This check is performed using the [`ulps_eq`] macro of the [approx] crate.
-
-    ```
-    use bounding_box::BoundingBox;
-
-    // bb1 covers bb2 depending on the selected tolerances
-    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
-    let bb2 = BoundingBox::new(0.0, 1.0001, 0.0, 1.0);
-
-    assert!(!bb1.eq(&bb2));
-    assert!(bb1.approx_eq(&bb2, 1e-3, 0));
-    assert!(!bb1.approx_eq(&bb2, 1e-6, 0));
-    ```
-
-    # Features
-
-    This function uses the [`ulps_eq`](https://docs.rs/approx/latest/approx/macro.ulps_eq.html)
-    macro of the [approx] crate, therefore the `approx ` feature needs to be enabled.
-     */
-    #[cfg(feature = "approx")]
-    pub fn approx_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
-        return ulps_eq!(
-            self.xmin(),
-            other.xmin(),
-            epsilon = epsilon,
-            max_ulps = max_ulps
-        ) && ulps_eq!(
-            self.xmax(),
-            other.xmax(),
-            epsilon = epsilon,
-            max_ulps = max_ulps
-        ) && ulps_eq!(
-            self.ymin(),
-            other.ymin(),
-            epsilon = epsilon,
-            max_ulps = max_ulps
-        ) && ulps_eq!(
-            self.ymax(),
-            other.ymax(),
-            epsilon = epsilon,
-            max_ulps = max_ulps
-        );
-    }
-
     /**
     Returns true if the bounding boxes intersect, i.e. if they both
     [cover](BoundingBox::covers) at least one common point.
@@ -817,7 +1377,7 @@ impl BoundingBox {
     ```
      */
     pub fn touches(&self, other: &Self) -> bool {
-        if self.overlaps(&other) {
+        if self.overlaps(other) || self.has_nan() || other.has_nan() {
             return false;
         } else {
             return self.xmin() == other.xmax()
@@ -827,60 +1387,6 @@ impl BoundingBox {
         }
     }
 
-    /**
-    Like [`BoundingBox::touches`], but with absolute and ULPs tolerances.
-
-    This variant of [`BoundingBox::touches`] allows specifying an absolute and
-    an [ULP](https://en.wikipedia.org/wiki/Unit_in_the_last_place) tolerance. These tolerances
-    are used to check if the boxes share at least one extremas "approximately".
-    This check is performed using the [`ulps_eq`](https://docs.rs/approx/latest/approx/macro.ulps_eq.html)
-    macro of the [approx] crate. Please see its documentation.
-
-    # Examples
-    ```
-    use bounding_box::BoundingBox;
-
-    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
-    let bb2 = BoundingBox::new(1.0001, 2.0, 0.0, 1.0);
-    assert!(!bb1.touches(&bb2));
-    assert!(bb1.approx_touches(&bb2, 1e-3, 0));
-    assert!(!bb1.approx_touches(&bb2, 1e-6, 0));
-    ```
-
-    # Features
-
-    This function uses the [`ulps_eq`](https://docs.rs/approx/latest/approx/macro.ulps_eq.html)
-    macro of the [approx] crate, therefore the `approx ` feature needs to be enabled.
-    */
-    #[cfg(feature = "approx")]
-    pub fn approx_touches(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
-        if self.overlaps(&other) {
-            return false;
-        } else {
-            return ulps_eq!(
-                self.xmin(),
-                other.xmax(),
-                epsilon = epsilon,
-                max_ulps = max_ulps
-            ) || ulps_eq!(
-                self.xmax(),
-                other.xmin(),
-                epsilon = epsilon,
-                max_ulps = max_ulps
-            ) || ulps_eq!(
-                self.ymin(),
-                other.ymax(),
-                epsilon = epsilon,
-                max_ulps = max_ulps
-            ) || ulps_eq!(
-                self.ymax(),
-                other.ymin(),
-                epsilon = epsilon,
-                max_ulps = max_ulps
-            );
-        }
-    }
-
     /**
     Returns the width of the bounding box.
 
@@ -892,7 +1398,7 @@ impl BoundingBox {
     assert_eq!(bb.width(), 2.0);
     ```
      */
-    pub fn width(&self) -> f64 {
+    pub fn width(&self) -> T {
         return self.xmax - self.xmin;
     }
 
@@ -907,7 +1413,7 @@ impl BoundingBox {
     assert_eq!(bb.height(), 5.0);
     ```
      */
-    pub fn height(&self) -> f64 {
+    pub fn height(&self) -> T {
         return self.ymax - self.ymin;
     }
 
@@ -920,11 +1426,15 @@ impl BoundingBox {
 
     let bb = BoundingBox::new(-1.0, 1.0, 2.0, 7.0);
     assert_eq!(bb.center(), [0.0, 4.5]);
+
+    // Large extremas do not overflow
+    let bb = BoundingBox::new(i32::MAX - 1, i32::MAX, 0, 1);
+    assert_eq!(bb.center(), [i32::MAX - 1, 0]);
     ```
      */
-    pub fn center(&self) -> [f64; 2] {
-        let x = 0.5 * (self.xmax + self.xmin);
-        let y = 0.5 * (self.ymax + self.ymin);
+    pub fn center(&self) -> [T; 2] {
+        let x = self.xmin.midpoint(self.xmax);
+        let y = self.ymin.midpoint(self.ymax);
         return [x, y];
     }
 
@@ -943,8 +1453,8 @@ impl BoundingBox {
     assert_eq!(bb.ymax(), 1.0);
     ```
      */
-    pub fn translate<T: Into<[f64; 2]>>(&mut self, shift: T) -> () {
-        let shift: [f64; 2] = shift.into();
+    pub fn translate<P: Into<[T; 2]>>(&mut self, shift: P) {
+        let shift: [T; 2] = shift.into();
         self.xmin += shift[0];
         self.xmax += shift[0];
         self.ymin += shift[1];
@@ -980,55 +1490,309 @@ impl BoundingBox {
     assert_eq!(bb.ymax(), 5.0);
     ```
      */
-    pub fn scale(&mut self, factor: f64) -> () {
-        let dw = 0.5 * (factor - 1.0) * self.width();
-        let dh = 0.5 * (factor - 1.0) * self.height();
-        self.xmin = self.xmin - dw;
-        self.xmax = self.xmax + dw;
-        self.ymin = self.ymin - dh;
-        self.ymax = self.ymax + dh;
+    pub fn scale(&mut self, factor: T) {
+        let two = T::ONE + T::ONE;
+        let dw = (factor - T::ONE) * self.width() / two;
+        let dh = (factor - T::ONE) * self.height() / two;
+        self.xmin -= dw;
+        self.xmax += dw;
+        self.ymin -= dh;
+        self.ymax += dh;
     }
 
     /**
-    Remove any singular dimensions by "buffering" them with `add_to_extr`.
+    Scales `self` by the factors `[fx, fy]` in x- and y-direction while keeping
+    the point `anchor` fixed.
 
-    The value `add_to_extr` is applied to both the minimum and the maximum value of a singular dimension.
-    For example, if the bounding box width is 0 (`xmin` = `xmax`) and `add_to_extr = 1.0`, the new width will be 2.0.
+    In contrast to [`BoundingBox::scale`], the factors for the two directions
+    can differ and the anchor can be any point, e.g. a corner of the box. The
+    factors may also be zero or negative, in which case the box collapses or is
+    mirrored at the anchor.
 
     # Examples
     ```
     use bounding_box::BoundingBox;
 
-    let mut bb = BoundingBox::new(0.0, 0.0, -1.0, 1.0);
-    assert_eq!(bb.width(), 0.0);
+    let mut bb = BoundingBox::new(1.0, 2.0, 1.0, 3.0);
 
-    bb.remove_singular_dimensions(1.0);
-    assert_eq!(bb.width(), 2.0);
-    assert_eq!(bb.xmin(), -1.0);
-    assert_eq!(bb.xmax(), 1.0);
+    // Keep the lower left corner fixed
+    bb.scale_xy([2.0, 0.5], [1.0, 1.0]);
+    assert_eq!(bb, BoundingBox::new(1.0, 3.0, 1.0, 2.0));
 
-    // =================================================
+    // Negative factor mirrors the box at the anchor
+    bb.scale_xy([-1.0, 1.0], [0.0, 0.0]);
+    assert_eq!(bb, BoundingBox::new(-3.0, -1.0, 1.0, 2.0));
+    ```
+     */
+    pub fn scale_xy<F: Into<[T; 2]>, P: Into<[T; 2]>>(&mut self, factors: F, anchor: P) {
+        let factors: [T; 2] = factors.into();
+        let anchor: [T; 2] = anchor.into();
+        let scale = |min: T, max: T, factor: T, anchor: T| {
+            let a = anchor + factor * (min - anchor);
+            let b = anchor + factor * (max - anchor);
+            if a <= b {
+                return (a, b);
+            }
+            return (b, a);
+        };
+        (self.xmin, self.xmax) = scale(self.xmin, self.xmax, factors[0], anchor[0]);
+        (self.ymin, self.ymax) = scale(self.ymin, self.ymax, factors[1], anchor[1]);
+    }
 
-    let mut bb = BoundingBox::new(-1.0, 1.0, 2.0, 2.0);
-    assert_eq!(bb.height(), 0.0);
+    /**
+    Returns a copy of `self` mirrored at the vertical line through `x`.
 
-    bb.remove_singular_dimensions(3.0);
-    assert_eq!(bb.height(), 6.0);
-    assert_eq!(bb.ymin(), -1.0);
-    assert_eq!(bb.ymax(), 5.0);
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(1.0, 2.0, 0.0, 1.0);
+    assert_eq!(bb.mirrored_x(0.0), BoundingBox::new(-2.0, -1.0, 0.0, 1.0));
+    assert_eq!(bb.mirrored_x(2.0), BoundingBox::new(2.0, 3.0, 0.0, 1.0));
+    ```
+     */
+    pub fn mirrored_x(&self, x: T) -> Self {
+        return BoundingBox {
+            xmin: x + x - self.xmax,
+            xmax: x + x - self.xmin,
+            ymin: self.ymin,
+            ymax: self.ymax,
+        };
+    }
+
+    /**
+    Returns a copy of `self` mirrored at the horizontal line through `y`.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 1.0, 1.0, 2.0);
+    assert_eq!(bb.mirrored_y(0.0), BoundingBox::new(0.0, 1.0, -2.0, -1.0));
+    ```
+     */
+    pub fn mirrored_y(&self, y: T) -> Self {
+        return BoundingBox {
+            xmin: self.xmin,
+            xmax: self.xmax,
+            ymin: y + y - self.ymax,
+            ymax: y + y - self.ymin,
+        };
+    }
+
+    /**
+    Remove any singular dimensions by "buffering" them with `add_to_extr`.
+
+    The value `add_to_extr` is applied to both the minimum and the maximum value of a singular dimension.
+    For example, if the bounding box width is 0 (`xmin` = `xmax`) and `add_to_extr = 1.0`, the new width will be 2.0.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let mut bb = BoundingBox::new(0.0, 0.0, -1.0, 1.0);
+    assert_eq!(bb.width(), 0.0);
+
+    bb.remove_singular_dimensions(1.0);
+    assert_eq!(bb.width(), 2.0);
+    assert_eq!(bb.xmin(), -1.0);
+    assert_eq!(bb.xmax(), 1.0);
+
+    // =================================================
+
+    let mut bb = BoundingBox::new(-1.0, 1.0, 2.0, 2.0);
+    assert_eq!(bb.height(), 0.0);
+
+    bb.remove_singular_dimensions(3.0);
+    assert_eq!(bb.height(), 6.0);
+    assert_eq!(bb.ymin(), -1.0);
+    assert_eq!(bb.ymax(), 5.0);
     ```
      */
-    pub fn remove_singular_dimensions(&mut self, add_to_extr: f64) {
-        if self.width() == 0.0 {
+    pub fn remove_singular_dimensions(&mut self, add_to_extr: T) {
+        if self.width() == T::ZERO {
             self.xmin -= add_to_extr;
             self.xmax += add_to_extr;
         }
-        if self.height() == 0.0 {
+        if self.height() == T::ZERO {
             self.ymin -= add_to_extr;
             self.ymax += add_to_extr;
         }
     }
 
+    /**
+    Returns the point covered by `self` which is closest to the given point.
+
+    If the point is [covered](BoundingBox::covers_point) by `self`, it is
+    returned unchanged. Otherwise, its coordinates are clamped to the extremas
+    of `self`, resulting in a point on the boundary.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    assert_eq!(bb.closest_point([0.5, 0.5]), [0.5, 0.5]);
+    assert_eq!(bb.closest_point([2.0, 0.5]), [1.0, 0.5]);
+    assert_eq!(bb.closest_point([-1.0, 3.0]), [0.0, 1.0]);
+    ```
+     */
+    pub fn closest_point<P: Into<[T; 2]>>(&self, point: P) -> [T; 2] {
+        let point: [T; 2] = point.into();
+        let clamp = |val: T, min: T, max: T| {
+            if val < min {
+                min
+            } else if val > max {
+                max
+            } else {
+                val
+            }
+        };
+        return [
+            clamp(point[0], self.xmin, self.xmax),
+            clamp(point[1], self.ymin, self.ymax),
+        ];
+    }
+
+    /**
+    Returns the squared euclidian distance between `self` and the given point.
+
+    The distance is measured to the [closest point](BoundingBox::closest_point)
+    of `self` and is therefore zero if the point is
+    [covered](BoundingBox::covers_point) by `self`. Since this function does
+    not need to calculate a square root, it is cheaper than
+    [`BoundingBox::distance_to_point`] and should be preferred e.g. for
+    comparing distances.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    assert_eq!(bb.squared_distance_to_point([0.5, 0.5]), 0.0);
+    assert_eq!(bb.squared_distance_to_point([3.0, 0.5]), 4.0);
+    assert_eq!(bb.squared_distance_to_point([4.0, 5.0]), 25.0);
+    ```
+     */
+    pub fn squared_distance_to_point<P: Into<[T; 2]>>(&self, point: P) -> T {
+        let point: [T; 2] = point.into();
+        let closest = self.closest_point(point);
+        let dx = closest[0] - point[0];
+        let dy = closest[1] - point[1];
+        return dx * dx + dy * dy;
+    }
+
+    /**
+    Returns the euclidian distance between `self` and the given point.
+
+    The distance is measured to the [closest point](BoundingBox::closest_point)
+    of `self` and is therefore zero if the point is
+    [covered](BoundingBox::covers_point) by `self`. If only the relative order
+    of distances is of interest, [`BoundingBox::squared_distance_to_point`] is
+    cheaper.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    assert_eq!(bb.distance_to_point([0.5, 0.5]), 0.0);
+    assert_eq!(bb.distance_to_point([1.0, 3.0]), 2.0);
+    assert_eq!(bb.distance_to_point([4.0, 5.0]), 5.0);
+    ```
+     */
+    pub fn distance_to_point<P: Into<[T; 2]>>(&self, point: P) -> T {
+        return self.squared_distance_to_point(point).sqrt();
+    }
+
+    /**
+    Returns the euclidian distance between `self` and the point covered by
+    `self` which is farthest away from the given point. This is always one of
+    the corners of `self`.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 3.0, 0.0, 4.0);
+    assert_eq!(bb.max_distance_to_point([0.0, 0.0]), 5.0);
+    assert_eq!(bb.max_distance_to_point([3.0, 4.0]), 5.0);
+    assert_eq!(bb.max_distance_to_point([6.0, 8.0]), 10.0);
+    ```
+     */
+    pub fn max_distance_to_point<P: Into<[T; 2]>>(&self, point: P) -> T {
+        let point: [T; 2] = point.into();
+        let farthest = |val: T, min: T, max: T| {
+            let to_min = if val > min { val - min } else { min - val };
+            let to_max = if val > max { val - max } else { max - val };
+            if to_min > to_max { to_min } else { to_max }
+        };
+        let dx = farthest(point[0], self.xmin, self.xmax);
+        let dy = farthest(point[1], self.ymin, self.ymax);
+        return (dx * dx + dy * dy).sqrt();
+    }
+
+    /**
+    Returns the squared euclidian distance between `self` and `other`.
+
+    The distance between two bounding boxes is the smallest distance between
+    any two points covered by the respective boxes. It is therefore zero if the
+    boxes [intersect](BoundingBox::intersects).
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox::new(4.0, 5.0, 5.0, 6.0);
+    assert_eq!(bb1.squared_distance_to_box(&bb2), 25.0);
+    ```
+     */
+    pub fn squared_distance_to_box(&self, other: &Self) -> T {
+        let gap = |min_a: T, max_a: T, min_b: T, max_b: T| {
+            if max_a < min_b {
+                min_b - max_a
+            } else if max_b < min_a {
+                min_a - max_b
+            } else {
+                T::ZERO
+            }
+        };
+        let dx = gap(self.xmin, self.xmax, other.xmin, other.xmax);
+        let dy = gap(self.ymin, self.ymax, other.ymin, other.ymax);
+        return dx * dx + dy * dy;
+    }
+
+    /**
+    Returns the euclidian distance between `self` and `other`.
+
+    The distance between two bounding boxes is the smallest distance between
+    any two points covered by the respective boxes. It is therefore zero if the
+    boxes [intersect](BoundingBox::intersects).
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+
+    // Boxes are separated along the x-axis
+    let bb2 = BoundingBox::new(3.0, 4.0, 0.5, 2.0);
+    assert_eq!(bb1.distance_to_box(&bb2), 2.0);
+
+    // Boxes are separated along both axes
+    let bb2 = BoundingBox::new(4.0, 5.0, 5.0, 6.0);
+    assert_eq!(bb1.distance_to_box(&bb2), 5.0);
+
+    // Boxes are touching
+    let bb2 = BoundingBox::new(1.0, 2.0, 1.0, 2.0);
+    assert_eq!(bb1.distance_to_box(&bb2), 0.0);
+    ```
+     */
+    pub fn distance_to_box(&self, other: &Self) -> T {
+        return self.squared_distance_to_box(other).sqrt();
+    }
+
     /**
     Returns true if the bounding box is finite.
 
@@ -1047,20 +1811,324 @@ impl BoundingBox {
             && self.ymin.is_finite()
             && self.ymax.is_finite();
     }
+
+    /**
+    Losslessly converts the bounding box into a bounding box with a different
+    scalar type `U`.
+
+    This conversion is only available if `U` implements [`From<T>`], e.g. for
+    `f32` -> `f64` or `i32` -> `i64`. Use [`BoundingBox::cast`] for lossy
+    conversions.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0i32, 640, 0, 480);
+    let bb_f64: BoundingBox<f64> = bb.convert();
+    assert_eq!(bb_f64, BoundingBox::new(0.0, 640.0, 0.0, 480.0));
+    ```
+     */
+    pub fn convert<U: Scalar + From<T>>(self) -> BoundingBox<U> {
+        return BoundingBox {
+            xmin: self.xmin.into(),
+            xmax: self.xmax.into(),
+            ymin: self.ymin.into(),
+            ymax: self.ymax.into(),
+        };
+    }
+
+    /**
+    Converts the bounding box into a bounding box with a different scalar type
+    `U`. This conversion may be lossy.
+
+    The extremas are converted via `f64` using [`Scalar::to_f64`] and
+    [`Scalar::from_f64`]. For example, casting to an integer type rounds the
+    extremas towards zero. Since these conversions are monotonic, the
+    invariants `xmin <= xmax` and `ymin <= ymax` are preserved.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(-0.5, 2.7, 1.2, 3.9);
+    let bb_i32: BoundingBox<i32> = bb.cast();
+    assert_eq!(bb_i32, BoundingBox::new(0, 2, 1, 3));
+    ```
+     */
+    pub fn cast<U: Scalar>(self) -> BoundingBox<U> {
+        return BoundingBox {
+            xmin: U::from_f64(self.xmin.to_f64()),
+            xmax: U::from_f64(self.xmax.to_f64()),
+            ymin: U::from_f64(self.ymin.to_f64()),
+            ymax: U::from_f64(self.ymax.to_f64()),
+        };
+    }
 }
 
-impl From<[f64; 2]> for BoundingBox {
-    fn from(v: [f64; 2]) -> Self {
-        return (&v).into();
+#[cfg(feature = "approx")]
+impl<T: Scalar + UlpsEq<Epsilon = T>> BoundingBox<T> {
+    /**
+    Like [`BoundingBox::covers_point`], but with absolute and ULPs tolerances.
+
+    This variant of [`BoundingBox::covers_point`] allows specifying an absolute
+    and an [ULP](https://en.wikipedia.org/wiki/Unit_in_the_last_place)
+    tolerance. These tolerances  are used to check if the given point lies
+    "approximately" on an edge of the bounding box.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+
+    // Exact check: Point is outside the bounding box
+    assert!(!bb.covers_point([1.0001, 1.0]));
+
+    // Check using tolerances: Point is inside bounding box
+    assert!(bb.approx_covers_point([1.0001, 1.0], 1e-3, 0));
+
+    // Check using a finer tolerance: Point is outside the bounding box
+    assert!(!bb.approx_covers_point([1.0001, 1.0], 1e-6, 0));
+    ```
+
+    # Features
+
+    This function uses the [`ulps_eq`] macro of the [approx] crate, therefore
+    the `approx` feature needs to be enabled.
+     */
+    pub fn approx_covers_point<P: Into<[T; 2]>>(
+        &self,
+        point: P,
+        epsilon: T,
+        max_ulps: u32,
+    ) -> bool {
+        let point: [T; 2] = point.into();
+        return (self.xmin < point[0]
+            || ulps_eq!(self.xmin, point[0], epsilon = epsilon, max_ulps = max_ulps))
+            && (self.ymin < point[1]
+                || ulps_eq!(self.ymin, point[1], epsilon = epsilon, max_ulps = max_ulps))
+            && (self.xmax > point[0]
+                || ulps_eq!(self.xmax, point[0], epsilon = epsilon, max_ulps = max_ulps))
+            && (self.ymax > point[1]
+                || ulps_eq!(self.ymax, point[1], epsilon = epsilon, max_ulps = max_ulps));
+    }
+
+    /**
+    Like [`BoundingBox::covers`], but with absolute and ULPs tolerances.
+
+    This variant of [`BoundingBox::covers`] allows specifying an absolute and
+    an [ULP](https://en.wikipedia.org/wiki/Unit_in_the_last_place) tolerance.
+    These tolerances are used to check if the extremas of the boxes are
+    "approximately" equal.
+
+    ```
+    use bounding_box::BoundingBox;
+
+    // bb1 covers bb2 depending on the selected tolerances
+    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox::new(0.0, 1.0001, 0.0, 0.5);
+
+    assert!(!bb1.covers(&bb2));
+    assert!(bb1.approx_covers(&bb2, 1e-3, 0));
+    assert!(!bb1.approx_covers(&bb2, 1e-6, 0));
+    ```
+
+    # Features
+
+    This function uses the [`ulps_eq`] macro of the [approx] crate, therefore
+    the `approx` feature needs to be enabled.
+    */
+    pub fn approx_covers(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        return (self.xmin < other.xmin
+            || ulps_eq!(
+                self.xmin,
+                other.xmin,
+                epsilon = epsilon,
+                max_ulps = max_ulps
+            ))
+            && (self.ymin < other.ymin
+                || ulps_eq!(
+                    self.ymin,
+                    other.ymin,
+                    epsilon = epsilon,
+                    max_ulps = max_ulps
+                ))
+            && (self.xmax > other.xmax
+                || ulps_eq!(
+                    self.xmax,
+                    other.xmax,
+                    epsilon = epsilon,
+                    max_ulps = max_ulps
+                ))
+            && (self.ymax > other.ymax
+                || ulps_eq!(
+                    self.ymax,
+                    other.ymax,
+                    epsilon = epsilon,
+                    max_ulps = max_ulps
+                ));
+    }
+
+    /**
+    Check if the two bounding boxes are approximately equal.
+
+    This check is performed using the [`ulps_eq`] macro of the [approx] crate.
+
+    ```
+    use bounding_box::BoundingBox;
+
+    // bb1 covers bb2 depending on the selected tolerances
+    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox::new(0.0, 1.0001, 0.0, 1.0);
+
+    assert!(!bb1.eq(&bb2));
+    assert!(bb1.approx_eq(&bb2, 1e-3, 0));
+    assert!(!bb1.approx_eq(&bb2, 1e-6, 0));
+    ```
+
+    # Features
+
+    This function uses the [`ulps_eq`](https://docs.rs/approx/latest/approx/macro.ulps_eq.html)
+    macro of the [approx] crate, therefore the `approx ` feature needs to be enabled.
+     */
+    pub fn approx_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        return ulps_eq!(
+            self.xmin(),
+            other.xmin(),
+            epsilon = epsilon,
+            max_ulps = max_ulps
+        ) && ulps_eq!(
+            self.xmax(),
+            other.xmax(),
+            epsilon = epsilon,
+            max_ulps = max_ulps
+        ) && ulps_eq!(
+            self.ymin(),
+            other.ymin(),
+            epsilon = epsilon,
+            max_ulps = max_ulps
+        ) && ulps_eq!(
+            self.ymax(),
+            other.ymax(),
+            epsilon = epsilon,
+            max_ulps = max_ulps
+        );
+    }
+
+    /**
+    Like [`BoundingBox::touches`], but with absolute and ULPs tolerances.
+
+    This variant of [`BoundingBox::touches`] allows specifying an absolute and
+    an [ULP](https://en.wikipedia.org/wiki/Unit_in_the_last_place) tolerance. These tolerances
+    are used to check if the boxes share at least one extremas "approximately".
+    This check is performed using the [`ulps_eq`](https://docs.rs/approx/latest/approx/macro.ulps_eq.html)
+    macro of the [approx] crate. Please see its documentation.
+
+    # Examples
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox::new(1.0001, 2.0, 0.0, 1.0);
+    assert!(!bb1.touches(&bb2));
+    assert!(bb1.approx_touches(&bb2, 1e-3, 0));
+    assert!(!bb1.approx_touches(&bb2, 1e-6, 0));
+    ```
+
+    # Features
+
+    This function uses the [`ulps_eq`](https://docs.rs/approx/latest/approx/macro.ulps_eq.html)
+    macro of the [approx] crate, therefore the `approx ` feature needs to be enabled.
+    */
+    pub fn approx_touches(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        if self.overlaps(other) || self.has_nan() || other.has_nan() {
+            return false;
+        } else {
+            return ulps_eq!(
+                self.xmin(),
+                other.xmax(),
+                epsilon = epsilon,
+                max_ulps = max_ulps
+            ) || ulps_eq!(
+                self.xmax(),
+                other.xmin(),
+                epsilon = epsilon,
+                max_ulps = max_ulps
+            ) || ulps_eq!(
+                self.ymin(),
+                other.ymax(),
+                epsilon = epsilon,
+                max_ulps = max_ulps
+            ) || ulps_eq!(
+                self.ymax(),
+                other.ymin(),
+                epsilon = epsilon,
+                max_ulps = max_ulps
+            );
+        }
     }
 }
 
-impl From<&'_ [f64; 2]> for BoundingBox {
-    fn from(v: &'_ [f64; 2]) -> Self {
-        return BoundingBox::new(v[0], v[0], v[1], v[1]);
+/**
+Selects which predicate is used to decide whether two bounding boxes are
+colliding, e.g. when searching for pairs of colliding boxes with
+[`SpatialGrid::pairs`] or [`sweep_and_prune`].
+
+```
+use bounding_box::{BoundingBox, IntersectionTest};
+
+let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+let bb2 = BoundingBox::new(1.0, 2.0, 0.0, 1.0);
+
+// The boxes are touching
+assert!(IntersectionTest::Intersects.evaluate(&bb1, &bb2));
+assert!(!IntersectionTest::Overlaps.evaluate(&bb1, &bb2));
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IntersectionTest {
+    /// Use [`BoundingBox::intersects`], i.e. boxes which are just touching
+    /// each other are colliding.
+    #[default]
+    Intersects,
+    /// Use [`BoundingBox::overlaps`], i.e. boxes which are just touching each
+    /// other are not colliding.
+    Overlaps,
+}
+
+impl IntersectionTest {
+    /**
+    Evaluates the selected predicate for the two bounding boxes.
+     */
+    pub fn evaluate<T: Scalar>(&self, first: &BoundingBox<T>, second: &BoundingBox<T>) -> bool {
+        match self {
+            IntersectionTest::Intersects => return first.intersects(second),
+            IntersectionTest::Overlaps => return first.overlaps(second),
+        }
     }
 }
 
+macro_rules! impl_from_point {
+    ($($t:ty),*) => {
+        $(
+            impl From<[$t; 2]> for BoundingBox<$t> {
+                fn from(v: [$t; 2]) -> Self {
+                    return (&v).into();
+                }
+            }
+
+            impl From<&'_ [$t; 2]> for BoundingBox<$t> {
+                fn from(v: &'_ [$t; 2]) -> Self {
+                    return BoundingBox::new(v[0], v[0], v[1], v[1]);
+                }
+            }
+        )*
+    };
+}
+
+impl_from_point!(f32, f64, i8, i16, i32, i64, i128, isize);
+
 /**
 This trait provides a standardized way of deriving a [`BoundingBox`] from
 another type `T `with the [`bounding_box`](ToBoundingBox::bounding_box) method.
@@ -1089,7 +2157,7 @@ let c = Circle {center: [0.0, 0.0], radius: 1.0};
 assert_eq!(c.bounding_box(), BoundingBox::from(&c));
 ```
  */
-pub trait ToBoundingBox {
+pub trait ToBoundingBox<T: Scalar = f64> {
     /**
     Returns a bounding box for the implementor.
 
@@ -1120,11 +2188,17 @@ pub trait ToBoundingBox {
     assert_eq!(bb.ymax(), 1.0);
     ```
      */
-    fn bounding_box(&self) -> BoundingBox;
+    fn bounding_box(&self) -> BoundingBox<T>;
+}
+
+impl<T: Scalar> ToBoundingBox<T> for BoundingBox<T> {
+    fn bounding_box(&self) -> BoundingBox<T> {
+        return *self;
+    }
 }
 
-impl<T: ToBoundingBox> From<&T> for BoundingBox {
-    fn from(value: &T) -> Self {
+impl<T: Scalar, E: ToBoundingBox<T>> From<&E> for BoundingBox<T> {
+    fn from(value: &E) -> Self {
         value.bounding_box()
     }
 }
@@ -0,0 +1,236 @@
+/*!
+Defines the [`MaybeEmptyBox`], a [`BoundingBox`] which can also be empty.
+ */
+
+use std::iter::Sum;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{BoundingBox, Scalar};
+
+/**
+A [`BoundingBox`] which may also be empty.
+
+A [`BoundingBox`] always covers at least one point, which is why e.g.
+[`BoundingBox::from_points`] returns an [`Option`]. When accumulating bounds in
+a loop, this leads to repeated `Option` handling. A [`MaybeEmptyBox`] instead
+starts out [empty](MaybeEmptyBox::EMPTY) and grows with each added point or box.
+The empty box is the identity element of [`union`](MaybeEmptyBox::union) and
+the absorbing element of [`intersection`](MaybeEmptyBox::intersection), so it
+can be used as the starting value of folds. It is also the [`Default`] value,
+and iterators over bounding boxes can be summed up into a [`MaybeEmptyBox`]
+via [`Sum`], which computes their union.
+
+In accordance with the [NaN policy](BoundingBox#nan-policy) of
+[`BoundingBox::from_points`], points and boxes containing NaN are ignored when
+extending a [`MaybeEmptyBox`].
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, MaybeEmptyBox};
+
+let mut bounds = MaybeEmptyBox::EMPTY;
+assert!(bounds.is_empty());
+
+for point in [[1.0, 2.0], [-1.0, 0.5], [0.0, 3.0]] {
+    bounds.extend_point(point);
+}
+bounds.extend_box(&BoundingBox::new(0.0, 2.0, 0.0, 1.0));
+assert_eq!(bounds.get(), Some(BoundingBox::new(-1.0, 2.0, 0.0, 3.0)));
+
+// Union of many boxes without any `Option` handling
+let boxes = vec![BoundingBox::new(0.0, 1.0, 0.0, 1.0), BoundingBox::new(2.0, 3.0, 2.0, 3.0)];
+let union: MaybeEmptyBox = boxes.iter().sum();
+assert_eq!(union, BoundingBox::new(0.0, 3.0, 0.0, 3.0).into());
+
+let nothing: MaybeEmptyBox = Vec::<BoundingBox>::new().into_iter().sum();
+assert!(nothing.is_empty());
+```
+
+# Features
+
+This struct can be serialized / deserialized if the `serde` feature is enabled.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: Scalar + Deserialize<'de>"))
+)]
+pub struct MaybeEmptyBox<T = f64>(Option<BoundingBox<T>>);
+
+impl<T: Scalar> MaybeEmptyBox<T> {
+    /**
+    The empty box, which covers no point at all.
+     */
+    pub const EMPTY: Self = MaybeEmptyBox(None);
+
+    /**
+    Returns true if the box is empty.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, MaybeEmptyBox};
+
+    assert!(MaybeEmptyBox::<f64>::EMPTY.is_empty());
+    assert!(!MaybeEmptyBox::from(BoundingBox::new(0.0, 0.0, 0.0, 0.0)).is_empty());
+    ```
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.0.is_none();
+    }
+
+    /**
+    Returns the underlying [`BoundingBox`] or `None` if the box is empty.
+     */
+    pub fn get(&self) -> Option<BoundingBox<T>> {
+        return self.0;
+    }
+
+    /**
+    Enlarges the box so that it covers `point`. If the box is empty, it becomes
+    the degenerate box consisting of `point` only. Points with a NaN coordinate
+    are ignored.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, MaybeEmptyBox};
+
+    let mut bounds = MaybeEmptyBox::EMPTY;
+    bounds.extend_point([1.0, 2.0]);
+    assert_eq!(bounds.get(), Some(BoundingBox::new(1.0, 1.0, 2.0, 2.0)));
+
+    bounds.extend_point([0.0, f64::NAN]);
+    assert_eq!(bounds.get(), Some(BoundingBox::new(1.0, 1.0, 2.0, 2.0)));
+    ```
+     */
+    pub fn extend_point<P: Into<[T; 2]>>(&mut self, point: P) {
+        let point: [T; 2] = point.into();
+        if point[0].is_nan() || point[1].is_nan() {
+            return;
+        }
+        match self.0.as_mut() {
+            Some(bb) => bb.include_point(point),
+            None => self.0 = Some(BoundingBox::new(point[0], point[0], point[1], point[1])),
+        }
+    }
+
+    /**
+    Enlarges the box so that it covers `bb`. If the box is empty, it becomes
+    `bb`. Boxes with NaN extremas are ignored.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, MaybeEmptyBox};
+
+    let mut bounds = MaybeEmptyBox::EMPTY;
+    bounds.extend_box(&BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    bounds.extend_box(&BoundingBox::new(-1.0, 0.0, 0.5, 2.0));
+    assert_eq!(bounds.get(), Some(BoundingBox::new(-1.0, 1.0, 0.0, 2.0)));
+    ```
+     */
+    pub fn extend_box(&mut self, bb: &BoundingBox<T>) {
+        if bb.has_nan() {
+            return;
+        }
+        self.0 = Some(match self.0 {
+            Some(current) => current.union(bb),
+            None => *bb,
+        });
+    }
+
+    /**
+    Returns the union of `self` and `other`. The empty box is the identity
+    element of this operation.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, MaybeEmptyBox};
+
+    let bb = MaybeEmptyBox::from(BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    assert_eq!(bb.union(&MaybeEmptyBox::EMPTY), bb);
+    assert_eq!(MaybeEmptyBox::EMPTY.union(&bb), bb);
+    ```
+     */
+    pub fn union(&self, other: &Self) -> Self {
+        match (self.0, other.0) {
+            (Some(first), Some(second)) => return MaybeEmptyBox(Some(first.union(&second))),
+            (Some(bb), None) | (None, Some(bb)) => return MaybeEmptyBox(Some(bb)),
+            (None, None) => return Self::EMPTY,
+        }
+    }
+
+    /**
+    Returns the intersection of `self` and `other`, which is empty if the boxes
+    do not [intersect](BoundingBox::intersects). The empty box is the absorbing
+    element of this operation.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, MaybeEmptyBox};
+
+    let bb1 = MaybeEmptyBox::from(BoundingBox::new(0.0, 2.0, 0.0, 2.0));
+    let bb2 = MaybeEmptyBox::from(BoundingBox::new(1.0, 3.0, 1.0, 3.0));
+    assert_eq!(bb1.intersection(&bb2), BoundingBox::new(1.0, 2.0, 1.0, 2.0).into());
+    assert!(bb1.intersection(&MaybeEmptyBox::EMPTY).is_empty());
+    ```
+     */
+    pub fn intersection(&self, other: &Self) -> Self {
+        match (self.0, other.0) {
+            (Some(first), Some(second)) => return MaybeEmptyBox(first.intersection(&second)),
+            _ => return Self::EMPTY,
+        }
+    }
+}
+
+impl<T: Scalar> Default for MaybeEmptyBox<T> {
+    fn default() -> Self {
+        return Self::EMPTY;
+    }
+}
+
+impl<T: Scalar> From<BoundingBox<T>> for MaybeEmptyBox<T> {
+    fn from(bb: BoundingBox<T>) -> Self {
+        return MaybeEmptyBox(Some(bb));
+    }
+}
+
+impl<T: Scalar> From<Option<BoundingBox<T>>> for MaybeEmptyBox<T> {
+    fn from(bb: Option<BoundingBox<T>>) -> Self {
+        return MaybeEmptyBox(bb);
+    }
+}
+
+impl<T: Scalar> From<MaybeEmptyBox<T>> for Option<BoundingBox<T>> {
+    fn from(bb: MaybeEmptyBox<T>) -> Self {
+        return bb.0;
+    }
+}
+
+impl<T: Scalar> Sum<BoundingBox<T>> for MaybeEmptyBox<T> {
+    fn sum<I: Iterator<Item = BoundingBox<T>>>(iter: I) -> Self {
+        return iter.fold(Self::EMPTY, |mut acc, bb| {
+            acc.extend_box(&bb);
+            return acc;
+        });
+    }
+}
+
+impl<'a, T: Scalar> Sum<&'a BoundingBox<T>> for MaybeEmptyBox<T> {
+    fn sum<I: Iterator<Item = &'a BoundingBox<T>>>(iter: I) -> Self {
+        return iter.copied().sum();
+    }
+}
+
+impl<T: Scalar> Sum<MaybeEmptyBox<T>> for MaybeEmptyBox<T> {
+    fn sum<I: Iterator<Item = MaybeEmptyBox<T>>>(iter: I) -> Self {
+        return iter.fold(Self::EMPTY, |acc, bb| acc.union(&bb));
+    }
+}
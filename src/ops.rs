@@ -0,0 +1,143 @@
+/*!
+Implements iterator adaptors ([`FromIterator`], [`Extend`], [`Sum`]) and
+operators for [`BoundingBox`] and [`MaybeEmptyBox`]. See the
+[documentation of `BoundingBox`](BoundingBox#operators-and-iterator-adaptors)
+for an overview.
+ */
+
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Mul, MulAssign};
+
+use crate::{BoundingBox, MaybeEmptyBox, Scalar};
+
+impl<T: Scalar> BitOr for BoundingBox<T> {
+    type Output = BoundingBox<T>;
+
+    /// Returns the [union](BoundingBox::union) of both boxes.
+    fn bitor(self, rhs: Self) -> Self::Output {
+        return self.union(&rhs);
+    }
+}
+
+impl<T: Scalar> BitOrAssign for BoundingBox<T> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(&rhs);
+    }
+}
+
+impl<T: Scalar> BitAnd for BoundingBox<T> {
+    type Output = Option<BoundingBox<T>>;
+
+    /// Returns the [intersection](BoundingBox::intersection) of both boxes or
+    /// `None` if they do not intersect.
+    fn bitand(self, rhs: Self) -> Self::Output {
+        return self.intersection(&rhs);
+    }
+}
+
+impl<T: Scalar> Add<[T; 2]> for BoundingBox<T> {
+    type Output = BoundingBox<T>;
+
+    /// Returns a copy of `self` [translated](BoundingBox::translate) by `rhs`.
+    fn add(mut self, rhs: [T; 2]) -> Self::Output {
+        self.translate(rhs);
+        return self;
+    }
+}
+
+impl<T: Scalar> AddAssign<[T; 2]> for BoundingBox<T> {
+    fn add_assign(&mut self, rhs: [T; 2]) {
+        self.translate(rhs);
+    }
+}
+
+impl<T: Scalar> Mul<T> for BoundingBox<T> {
+    type Output = BoundingBox<T>;
+
+    /// Returns a copy of `self` [scaled](BoundingBox::scale) by `rhs` around
+    /// its center.
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.scale(rhs);
+        return self;
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for BoundingBox<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.scale(rhs);
+    }
+}
+
+impl<T: Scalar, P: Into<[T; 2]>> Extend<P> for BoundingBox<T> {
+    /// Enlarges `self` so that it covers all points of `iter`. Points with a
+    /// NaN coordinate are skipped, see [`BoundingBox::from_points`].
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        if let Some(bb) = BoundingBox::from_points(iter.into_iter()) {
+            *self = self.union(&bb);
+        }
+    }
+}
+
+impl<T: Scalar> Sum<BoundingBox<T>> for Option<BoundingBox<T>> {
+    /// Returns the union of all boxes or `None` if the iterator is empty, see
+    /// [`BoundingBox::from_bounded_entities`].
+    fn sum<I: Iterator<Item = BoundingBox<T>>>(iter: I) -> Self {
+        return BoundingBox::from_bounded_entities(iter);
+    }
+}
+
+impl<'a, T: Scalar> Sum<&'a BoundingBox<T>> for Option<BoundingBox<T>> {
+    /// Returns the union of all boxes or `None` if the iterator is empty, see
+    /// [`BoundingBox::from_bounded_entities`].
+    fn sum<I: Iterator<Item = &'a BoundingBox<T>>>(iter: I) -> Self {
+        return BoundingBox::from_bounded_entities(iter);
+    }
+}
+
+impl<T: Scalar> BitOr for MaybeEmptyBox<T> {
+    type Output = MaybeEmptyBox<T>;
+
+    /// Returns the [union](MaybeEmptyBox::union) of both boxes.
+    fn bitor(self, rhs: Self) -> Self::Output {
+        return self.union(&rhs);
+    }
+}
+
+impl<T: Scalar> BitOrAssign for MaybeEmptyBox<T> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(&rhs);
+    }
+}
+
+impl<T: Scalar> BitAnd for MaybeEmptyBox<T> {
+    type Output = MaybeEmptyBox<T>;
+
+    /// Returns the [intersection](MaybeEmptyBox::intersection) of both boxes.
+    fn bitand(self, rhs: Self) -> Self::Output {
+        return self.intersection(&rhs);
+    }
+}
+
+impl<T: Scalar> BitAndAssign for MaybeEmptyBox<T> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(&rhs);
+    }
+}
+
+impl<T: Scalar, P: Into<[T; 2]>> FromIterator<P> for MaybeEmptyBox<T> {
+    /// Creates the bounding box of all points of `iter` (empty if `iter` is
+    /// empty), see [`BoundingBox::from_points`].
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        return BoundingBox::from_points(iter.into_iter()).into();
+    }
+}
+
+impl<T: Scalar, P: Into<[T; 2]>> Extend<P> for MaybeEmptyBox<T> {
+    /// Enlarges `self` so that it covers all points of `iter`, see
+    /// [`MaybeEmptyBox::extend_point`].
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        for point in iter {
+            self.extend_point(point);
+        }
+    }
+}
@@ -0,0 +1,580 @@
+/*!
+Defines the [`OrientedBoundingBox`], a rectangle of arbitrary orientation.
+ */
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::f64::consts::FRAC_PI_2;
+
+use crate::hull::convex_hull;
+use crate::{BoundingBox, BoundingBoxError, Extremum, ToBoundingBox};
+
+/**
+A rectangle of arbitrary orientation, also known as oriented bounding box (OBB).
+
+An oriented bounding box is described by its center, its half-extents (half
+the width and half the height in its local coordinate system) and its angle.
+The angle (in radians) is the counterclockwise rotation of the local x-axis
+relative to the global x-axis. With an angle of zero, an oriented bounding box
+is equivalent to a [`BoundingBox`].
+
+For elongated entities which are not aligned with the coordinate axes, an
+oriented bounding box encloses the entity much tighter than a [`BoundingBox`].
+Oriented bounding boxes can be tested for intersection with each other and
+with [`BoundingBox`] instances using the
+[separating axis theorem](https://en.wikipedia.org/wiki/Hyperplane_separation_theorem).
+Via [`ToBoundingBox`], they can be used wherever a [`BoundingBox`] is expected,
+e.g. in [`BoundingBox::from_bounded_entities`].
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, OrientedBoundingBox};
+use std::f64::consts::FRAC_PI_4;
+
+// Diagonal bar from [0, 0] to [10, 10] with a width of 1
+let half_length = 50.0f64.sqrt();
+let bar = OrientedBoundingBox::new([5.0, 5.0], [half_length, 0.5], FRAC_PI_4);
+
+// The axis-aligned bounding box also covers the corners, which the bar misses
+let corner = BoundingBox::new(8.0, 10.0, 0.0, 2.0);
+assert!(bar.to_aabb().intersects(&corner));
+assert!(!bar.intersects_aabb(&corner));
+```
+
+# Features
+
+This struct can be serialized / deserialized if the `serde` feature is enabled.
+Deserialization validates the values like
+[`try_new`](OrientedBoundingBox::try_new) does.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "OrientedBoundingBoxData"))]
+pub struct OrientedBoundingBox {
+    center: [f64; 2],
+    half_extents: [f64; 2],
+    angle: f64,
+}
+
+/// Unvalidated serde representation of an [`OrientedBoundingBox`].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "OrientedBoundingBox")]
+struct OrientedBoundingBoxData {
+    center: [f64; 2],
+    half_extents: [f64; 2],
+    angle: f64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<OrientedBoundingBoxData> for OrientedBoundingBox {
+    type Error = &'static str;
+
+    fn try_from(data: OrientedBoundingBoxData) -> Result<Self, Self::Error> {
+        return OrientedBoundingBox::try_new(data.center, data.half_extents, data.angle).ok_or(
+            "invalid oriented bounding box: half-extents must not be negative and all values must be finite",
+        );
+    }
+}
+
+impl OrientedBoundingBox {
+    /**
+    Generates an oriented bounding box from its center, its half-extents
+    `[half_width, half_height]` and its angle in radians.
+
+    # Panics
+    Panics if one of the half-extents is negative or if any of the values is
+    NaN or infinite.
+
+    # Examples
+
+    ```
+    use bounding_box::OrientedBoundingBox;
+
+    let obb = OrientedBoundingBox::new([1.0, 2.0], [3.0, 1.0], 0.5);
+    assert_eq!(obb.center(), [1.0, 2.0]);
+    assert_eq!(obb.half_extents(), [3.0, 1.0]);
+    assert_eq!(obb.angle(), 0.5);
+    ```
+
+    This example panics because the half-width is negative:
+
+    ```should_panic
+    use bounding_box::OrientedBoundingBox;
+
+    let _ = OrientedBoundingBox::new([1.0, 2.0], [-3.0, 1.0], 0.5);
+    ```
+     */
+    pub fn new(center: [f64; 2], half_extents: [f64; 2], angle: f64) -> Self {
+        return Self::try_new(center, half_extents, angle)
+            .expect("half-extents must not be negative and all values must be finite");
+    }
+
+    /**
+    Like [`OrientedBoundingBox::new`], but returns `None` instead of panicking
+    if one of the half-extents is negative or if any of the values is NaN or
+    infinite.
+
+    # Examples
+
+    ```
+    use bounding_box::OrientedBoundingBox;
+
+    assert!(OrientedBoundingBox::try_new([0.0, 0.0], [1.0, 1.0], 0.0).is_some());
+    assert!(OrientedBoundingBox::try_new([0.0, 0.0], [1.0, -1.0], 0.0).is_none());
+    assert!(OrientedBoundingBox::try_new([0.0, 0.0], [f64::INFINITY, 1.0], 0.0).is_none());
+    assert!(OrientedBoundingBox::try_new([f64::NAN, 0.0], [1.0, 1.0], 0.0).is_none());
+    ```
+     */
+    pub fn try_new(center: [f64; 2], half_extents: [f64; 2], angle: f64) -> Option<Self> {
+        if !(half_extents[0] >= 0.0 && half_extents[1] >= 0.0) {
+            return None;
+        }
+        let values = [
+            center[0],
+            center[1],
+            half_extents[0],
+            half_extents[1],
+            angle,
+        ];
+        if !values.iter().all(|val| val.is_finite()) {
+            return None;
+        }
+        return Some(OrientedBoundingBox {
+            center,
+            half_extents,
+            angle,
+        });
+    }
+
+    /**
+    Returns the center of the box.
+     */
+    pub fn center(&self) -> [f64; 2] {
+        return self.center;
+    }
+
+    /**
+    Returns the half-extents `[half_width, half_height]` of the box in its local
+    coordinate system.
+     */
+    pub fn half_extents(&self) -> [f64; 2] {
+        return self.half_extents;
+    }
+
+    /**
+    Returns the counterclockwise rotation angle of the box in radians.
+     */
+    pub fn angle(&self) -> f64 {
+        return self.angle;
+    }
+
+    /**
+    Returns the width of the box along its local x-axis.
+     */
+    pub fn width(&self) -> f64 {
+        return 2.0 * self.half_extents[0];
+    }
+
+    /**
+    Returns the height of the box along its local y-axis.
+     */
+    pub fn height(&self) -> f64 {
+        return 2.0 * self.half_extents[1];
+    }
+
+    /**
+    Returns the area of the box.
+     */
+    pub fn area(&self) -> f64 {
+        return self.width() * self.height();
+    }
+
+    /**
+    Returns the local x- and y-axis of the box as unit vectors.
+
+    # Examples
+
+    ```
+    use bounding_box::OrientedBoundingBox;
+
+    let obb = OrientedBoundingBox::new([0.0, 0.0], [1.0, 1.0], 0.0);
+    assert_eq!(obb.axes(), [[1.0, 0.0], [0.0, 1.0]]);
+    ```
+     */
+    pub fn axes(&self) -> [[f64; 2]; 2] {
+        let (sin, cos) = self.angle.sin_cos();
+        return [[cos, sin], [-sin, cos]];
+    }
+
+    /**
+    Returns the four corners of the box in counterclockwise order, starting with
+    the corner at `-half_width` and `-half_height` in local coordinates.
+
+    # Examples
+
+    ```
+    use bounding_box::OrientedBoundingBox;
+
+    let obb = OrientedBoundingBox::new([1.0, 1.0], [2.0, 1.0], 0.0);
+    assert_eq!(obb.corners(), [[-1.0, 0.0], [3.0, 0.0], [3.0, 2.0], [-1.0, 2.0]]);
+    ```
+     */
+    pub fn corners(&self) -> [[f64; 2]; 4] {
+        let [u, v] = self.axes();
+        let [hx, hy] = self.half_extents;
+        let corner = |sx: f64, sy: f64| {
+            [
+                self.center[0] + sx * hx * u[0] + sy * hy * v[0],
+                self.center[1] + sx * hx * u[1] + sy * hy * v[1],
+            ]
+        };
+        return [
+            corner(-1.0, -1.0),
+            corner(1.0, -1.0),
+            corner(1.0, 1.0),
+            corner(-1.0, 1.0),
+        ];
+    }
+
+    /**
+    Returns the axis-aligned [`BoundingBox`] of `self`.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, OrientedBoundingBox};
+    use std::f64::consts::FRAC_PI_2;
+
+    let obb = OrientedBoundingBox::new([0.0, 0.0], [2.0, 1.0], FRAC_PI_2);
+    let aabb = obb.to_aabb();
+    assert!(aabb.approx_eq(&BoundingBox::new(-1.0, 1.0, -2.0, 2.0), 1e-12, 0));
+    ```
+     */
+    pub fn to_aabb(&self) -> BoundingBox<f64> {
+        let [u, v] = self.axes();
+        let [hx, hy] = self.half_extents;
+        let rx = hx * u[0].abs() + hy * v[0].abs();
+        let ry = hx * u[1].abs() + hy * v[1].abs();
+        return BoundingBox::new(
+            self.center[0] - rx,
+            self.center[0] + rx,
+            self.center[1] - ry,
+            self.center[1] + ry,
+        );
+    }
+
+    /// Returns the interval covered by the projection of `self` onto `axis`.
+    fn project(&self, axis: [f64; 2]) -> [f64; 2] {
+        let [u, v] = self.axes();
+        let [hx, hy] = self.half_extents;
+        let center = self.center[0] * axis[0] + self.center[1] * axis[1];
+        let radius = hx * (u[0] * axis[0] + u[1] * axis[1]).abs()
+            + hy * (v[0] * axis[0] + v[1] * axis[1]).abs();
+        return [center - radius, center + radius];
+    }
+
+    /**
+    Returns true if `self` covers the given point. Points on the boundary are
+    covered.
+
+    # Examples
+
+    ```
+    use bounding_box::OrientedBoundingBox;
+    use std::f64::consts::FRAC_PI_4;
+
+    let obb = OrientedBoundingBox::new([0.0, 0.0], [2.0, 0.5], FRAC_PI_4);
+    assert!(obb.covers_point([1.0, 1.0]));
+    assert!(!obb.covers_point([1.0, -1.0]));
+    ```
+     */
+    pub fn covers_point<P: Into<[f64; 2]>>(&self, point: P) -> bool {
+        let point: [f64; 2] = point.into();
+        let d = [point[0] - self.center[0], point[1] - self.center[1]];
+        let [u, v] = self.axes();
+        return (d[0] * u[0] + d[1] * u[1]).abs() <= self.half_extents[0]
+            && (d[0] * v[0] + d[1] * v[1]).abs() <= self.half_extents[1];
+    }
+
+    /**
+    Returns true if `self` and `other` intersect. Boxes which are just touching
+    each other are intersecting.
+
+    The test uses the separating axis theorem with the local axes of both
+    boxes as candidate axes.
+
+    # Examples
+
+    ```
+    use bounding_box::OrientedBoundingBox;
+    use std::f64::consts::FRAC_PI_4;
+
+    let a = OrientedBoundingBox::new([0.0, 0.0], [1.0, 1.0], 0.0);
+    let b = OrientedBoundingBox::new([2.0, 2.0], [1.5, 0.2], FRAC_PI_4);
+    let c = OrientedBoundingBox::new([2.0, 2.0], [1.5, 0.2], -FRAC_PI_4);
+
+    assert!(a.intersects(&b));
+    assert!(!a.intersects(&c));
+    ```
+     */
+    pub fn intersects(&self, other: &OrientedBoundingBox) -> bool {
+        for axis in self.axes().into_iter().chain(other.axes()) {
+            let [min_a, max_a] = self.project(axis);
+            let [min_b, max_b] = other.project(axis);
+            if max_a < min_b || max_b < min_a {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /**
+    Returns true if `self` and the axis-aligned `other` intersect. Boxes which
+    are just touching each other are intersecting.
+
+    The test uses the separating axis theorem with the coordinate axes and the
+    local axes of `self` as candidate axes. `other` may be infinite.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, OrientedBoundingBox};
+    use std::f64::consts::FRAC_PI_4;
+
+    let obb = OrientedBoundingBox::new([0.0, 0.0], [2.0, 0.2], FRAC_PI_4);
+    assert!(obb.intersects_aabb(&BoundingBox::new(0.5, 2.0, 0.5, 2.0)));
+    assert!(!obb.intersects_aabb(&BoundingBox::new(0.5, 2.0, -2.0, -0.5)));
+    assert!(obb.intersects_aabb(&BoundingBox::new(1.0, f64::INFINITY, 1.0, f64::INFINITY)));
+    ```
+     */
+    pub fn intersects_aabb(&self, other: &BoundingBox<f64>) -> bool {
+        if !self.to_aabb().intersects(other) {
+            return false;
+        }
+        for axis in self.axes() {
+            let [min_a, max_a] = self.project(axis);
+            // Project the axis-aligned box, skipping `0 * inf` terms
+            let (mut min_b, mut max_b) = (0.0, 0.0);
+            for (coeff, lower, upper) in [
+                (axis[0], other.xmin(), other.xmax()),
+                (axis[1], other.ymin(), other.ymax()),
+            ] {
+                if coeff != 0.0 {
+                    let (a, b) = (coeff * lower, coeff * upper);
+                    min_b += a.min(b);
+                    max_b += a.max(b);
+                }
+            }
+            if max_a < min_b || max_b < min_a {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /**
+    Returns the perimeter of the box.
+     */
+    pub fn perimeter(&self) -> f64 {
+        return 2.0 * (self.width() + self.height());
+    }
+
+    /**
+    Returns the oriented rectangle of minimum area which encloses all `points`
+    or `None` if `points` is empty or contains an infinite coordinate. Points
+    with a NaN coordinate are skipped.
+
+    The rectangle is found with the
+    [rotating calipers](https://en.wikipedia.org/wiki/Rotating_calipers)
+    method on the convex hull of the points: One side of the minimum-area
+    rectangle is collinear with an edge of the hull, hence it suffices to
+    evaluate the rectangle aligned with each hull edge. The rectangle area is
+    available via [`OrientedBoundingBox::area`] and its orientation via
+    [`OrientedBoundingBox::angle`], which is normalized to the range `[0, π/2)`.
+
+    For a single point or collinear points, the rectangle degenerates to a
+    point or a segment with zero height.
+
+    # Examples
+
+    ```
+    use bounding_box::OrientedBoundingBox;
+    use std::f64::consts::FRAC_PI_4;
+
+    // Points of a square rotated by 45°
+    let points = [[0.0, -1.0], [1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.2, 0.1]];
+    let rect = OrientedBoundingBox::min_area_from_points(points.into_iter()).unwrap();
+
+    assert!((rect.area() - 2.0).abs() < 1e-12);
+    assert!((rect.angle() - FRAC_PI_4).abs() < 1e-12);
+    assert!(rect.center()[0].abs() < 1e-12 && rect.center()[1].abs() < 1e-12);
+    ```
+     */
+    pub fn min_area_from_points<P: Into<[f64; 2]>, I: Iterator<Item = P>>(
+        points: I,
+    ) -> Option<Self> {
+        return Self::min_from_points(points, |obb| obb.area());
+    }
+
+    /**
+    Returns the oriented rectangle of minimum perimeter which encloses all
+    `points` or `None` if `points` is empty or contains an infinite coordinate.
+
+    This works like [`OrientedBoundingBox::min_area_from_points`], but the
+    rectangle perimeter is minimized instead of its area. The result is often,
+    but not always identical.
+
+    # Examples
+
+    ```
+    use bounding_box::OrientedBoundingBox;
+
+    let points = [[0.0, 0.0], [4.0, 0.0], [4.0, 1.0], [0.0, 1.0]];
+    let rect = OrientedBoundingBox::min_perimeter_from_points(points.into_iter()).unwrap();
+    assert_eq!(rect.perimeter(), 10.0);
+    assert_eq!(rect.angle(), 0.0);
+    ```
+     */
+    pub fn min_perimeter_from_points<P: Into<[f64; 2]>, I: Iterator<Item = P>>(
+        points: I,
+    ) -> Option<Self> {
+        return Self::min_from_points(points, |obb| obb.perimeter());
+    }
+
+    /// Rotating calipers over the convex hull of `points`, returning the
+    /// enclosing rectangle with the smallest `cost`.
+    fn min_from_points<P: Into<[f64; 2]>, I: Iterator<Item = P>>(
+        points: I,
+        cost: impl Fn(&Self) -> f64,
+    ) -> Option<Self> {
+        let hull = convex_hull(points);
+        if hull
+            .iter()
+            .any(|point| !point[0].is_finite() || !point[1].is_finite())
+        {
+            return None;
+        }
+        let sub = |a: [f64; 2], b: [f64; 2]| [a[0] - b[0], a[1] - b[1]];
+        let dot = |a: [f64; 2], b: [f64; 2]| a[0] * b[0] + a[1] * b[1];
+        match hull.len() {
+            0 => return None,
+            1 => return Some(OrientedBoundingBox::new(hull[0], [0.0, 0.0], 0.0)),
+            _ => (),
+        }
+
+        let n = hull.len();
+        let next = |idx: usize| (idx + 1) % n;
+        let mut best: Option<(f64, Self)> = None;
+        let (mut right, mut top, mut left) = (0, 0, 0);
+        for edge in 0..n {
+            let origin = hull[edge];
+            let d = sub(hull[next(edge)], origin);
+            let length = dot(d, d).sqrt();
+            let e = [d[0] / length, d[1] / length];
+            let normal = [-e[1], e[0]];
+
+            // Advance the calipers; each one moves at most once around the hull
+            let advance = |mut idx: usize, axis: [f64; 2], sign: f64| {
+                for _ in 0..n {
+                    if sign * dot(sub(hull[next(idx)], hull[idx]), axis) > 0.0 {
+                        idx = next(idx);
+                    } else {
+                        break;
+                    }
+                }
+                return idx;
+            };
+            if edge == 0 {
+                right = advance(0, e, 1.0);
+                top = advance(right, normal, 1.0);
+                left = advance(top, e, -1.0);
+            } else {
+                right = advance(right, e, 1.0);
+                top = advance(top, normal, 1.0);
+                left = advance(left, e, -1.0);
+            }
+
+            let max_e = dot(sub(hull[right], origin), e);
+            let min_e = dot(sub(hull[left], origin), e);
+            let height = dot(sub(hull[top], origin), normal);
+            let mid_e = 0.5 * (min_e + max_e);
+            let center = [
+                origin[0] + mid_e * e[0] + 0.5 * height * normal[0],
+                origin[1] + mid_e * e[1] + 0.5 * height * normal[1],
+            ];
+
+            // Normalize the angle to [0, π/2) by swapping the extents
+            let mut angle = e[1].atan2(e[0]);
+            let mut half_extents = [0.5 * (max_e - min_e), 0.5 * height];
+            while angle < 0.0 {
+                angle += FRAC_PI_2;
+                half_extents.swap(0, 1);
+            }
+            while angle >= FRAC_PI_2 {
+                angle -= FRAC_PI_2;
+                half_extents.swap(0, 1);
+            }
+            let rect = OrientedBoundingBox::new(center, half_extents, angle);
+            let rect_cost = cost(&rect);
+            if best
+                .as_ref()
+                .is_none_or(|(best_cost, _)| rect_cost < *best_cost)
+            {
+                best = Some((rect_cost, rect));
+            }
+        }
+        return best.map(|(_, rect)| rect);
+    }
+}
+
+impl TryFrom<BoundingBox<f64>> for OrientedBoundingBox {
+    type Error = BoundingBoxError;
+
+    /**
+    Converts an axis-aligned box into an oriented box with an angle of zero.
+    Returns [`BoundingBoxError::Infinite`] if the box is not finite, since an
+    oriented box needs a finite center.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, BoundingBoxError, Extremum, OrientedBoundingBox};
+
+    let obb = OrientedBoundingBox::try_from(BoundingBox::new(0.0, 4.0, 0.0, 2.0)).unwrap();
+    assert_eq!(obb.center(), [2.0, 1.0]);
+    assert_eq!(obb.half_extents(), [2.0, 1.0]);
+
+    let infinite = BoundingBox::new(f64::NEG_INFINITY, f64::INFINITY, 0.0, 1.0);
+    assert_eq!(
+        OrientedBoundingBox::try_from(infinite),
+        Err(BoundingBoxError::Infinite(Extremum::Xmin))
+    );
+    ```
+     */
+    fn try_from(bb: BoundingBox<f64>) -> Result<Self, Self::Error> {
+        let bb = BoundingBox::new_finite_checked(bb.xmin(), bb.xmax(), bb.ymin(), bb.ymax())?;
+
+        // Halving before subtracting keeps the half-extents finite even if the
+        // width or height overflow (e.g. from -f64::MAX to f64::MAX)
+        let half_extents = [
+            0.5 * bb.xmax() - 0.5 * bb.xmin(),
+            0.5 * bb.ymax() - 0.5 * bb.ymin(),
+        ];
+        return OrientedBoundingBox::try_new(bb.center(), half_extents, 0.0).ok_or(
+            if half_extents[0].is_finite() {
+                BoundingBoxError::Infinite(Extremum::Ymax)
+            } else {
+                BoundingBoxError::Infinite(Extremum::Xmax)
+            },
+        );
+    }
+}
+
+impl ToBoundingBox for OrientedBoundingBox {
+    fn bounding_box(&self) -> BoundingBox<f64> {
+        return self.to_aabb();
+    }
+}
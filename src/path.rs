@@ -0,0 +1,344 @@
+/*!
+Defines the [`Path`] of curve segments with cached bounding boxes.
+ */
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BoundingBox, CircularArc, CubicBezier, EllipticalArc, LineSegment, QuadraticBezier,
+    ToBoundingBox,
+};
+
+/**
+A single segment of a [`Path`].
+
+# Features
+
+This enum can be serialized / deserialized if the `serde` feature is enabled.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PathSegment {
+    /// A straight line segment.
+    Line(LineSegment),
+    /// A circular arc.
+    Arc(CircularArc),
+    /// An elliptical arc.
+    EllipticalArc(EllipticalArc),
+    /// A quadratic Bézier curve.
+    Quadratic(QuadraticBezier),
+    /// A cubic Bézier curve.
+    Cubic(CubicBezier),
+}
+
+impl PathSegment {
+    /**
+    Returns the point at the parameter `t`, where `t = 0` is the start and
+    `t = 1` is the end point of the segment.
+     */
+    pub fn point_at(&self, t: f64) -> [f64; 2] {
+        match self {
+            PathSegment::Line(segment) => return segment.point_at(t),
+            PathSegment::Arc(arc) => return arc.point_at(t),
+            PathSegment::EllipticalArc(arc) => return arc.point_at(t),
+            PathSegment::Quadratic(curve) => return curve.point_at(t),
+            PathSegment::Cubic(curve) => return curve.point_at(t),
+        }
+    }
+
+    /**
+    Translates the segment by the given `shift`.
+     */
+    pub fn translate<P: Into<[f64; 2]>>(&mut self, shift: P) {
+        let shift: [f64; 2] = shift.into();
+        match self {
+            PathSegment::Line(segment) => segment.translate(shift),
+            PathSegment::Arc(arc) => arc.translate(shift),
+            PathSegment::EllipticalArc(arc) => arc.translate(shift),
+            PathSegment::Quadratic(curve) => curve.translate(shift),
+            PathSegment::Cubic(curve) => curve.translate(shift),
+        }
+    }
+}
+
+impl ToBoundingBox for PathSegment {
+    fn bounding_box(&self) -> BoundingBox<f64> {
+        match self {
+            PathSegment::Line(segment) => return segment.bounding_box(),
+            PathSegment::Arc(arc) => return arc.bounding_box(),
+            PathSegment::EllipticalArc(arc) => return arc.bounding_box(),
+            PathSegment::Quadratic(curve) => return curve.bounding_box(),
+            PathSegment::Cubic(curve) => return curve.bounding_box(),
+        }
+    }
+}
+
+macro_rules! impl_from_curve {
+    ($($variant:ident($t:ty)),*) => {
+        $(
+            impl From<$t> for PathSegment {
+                fn from(curve: $t) -> Self {
+                    return PathSegment::$variant(curve);
+                }
+            }
+        )*
+    };
+}
+
+impl_from_curve!(
+    Line(LineSegment),
+    Arc(CircularArc),
+    EllipticalArc(EllipticalArc),
+    Quadratic(QuadraticBezier),
+    Cubic(CubicBezier)
+);
+
+/**
+A sequence of line, arc and Bézier segments with cached bounding boxes.
+
+The tight [`BoundingBox`] of each segment is computed once when the segment is
+added and stored alongside the segment. The bounding box of the whole path is
+kept up to date incrementally when segments are [appended](Path::push),
+[replaced](Path::set) or [translated](Path::translate_segment), so that
+[`ToBoundingBox::bounding_box`] is a cheap lookup. The cached boxes are also
+used to find the segments intersecting a query box without evaluating any
+curves.
+
+The segments do not need to be connected.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, CircularArc, LineSegment, Path, ToBoundingBox};
+use std::f64::consts::PI;
+
+let mut path = Path::new();
+path.push(LineSegment::new([0.0, 0.0], [2.0, 0.0]));
+path.push(CircularArc::new([2.0, 1.0], 1.0, -PI / 2.0, PI));
+path.push(LineSegment::new([2.0, 2.0], [0.0, 2.0]));
+
+assert!(path.bounding_box().approx_eq(&BoundingBox::new(0.0, 3.0, 0.0, 2.0), 1e-12, 0));
+
+// Only the arc reaches into this box
+let query = BoundingBox::new(2.5, 4.0, 0.5, 1.5);
+let hits: Vec<usize> = path.query_intersecting(&query).map(|(idx, _)| idx).collect();
+assert_eq!(hits, vec![1]);
+```
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Path {
+    segments: Vec<PathSegment>,
+    boxes: Vec<BoundingBox<f64>>,
+    bounds: Option<BoundingBox<f64>>,
+}
+
+impl Path {
+    /**
+    Creates an empty path.
+     */
+    pub fn new() -> Self {
+        return Path {
+            segments: Vec::new(),
+            boxes: Vec::new(),
+            bounds: None,
+        };
+    }
+
+    /**
+    Returns the number of segments.
+     */
+    pub fn len(&self) -> usize {
+        return self.segments.len();
+    }
+
+    /**
+    Returns true if the path does not contain any segments.
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.segments.is_empty();
+    }
+
+    /**
+    Returns all segments of the path.
+     */
+    pub fn segments(&self) -> &[PathSegment] {
+        return &self.segments;
+    }
+
+    /**
+    Returns the segment with the given index.
+     */
+    pub fn get(&self, index: usize) -> Option<&PathSegment> {
+        return self.segments.get(index);
+    }
+
+    /**
+    Returns the cached bounding box of the segment with the given index.
+     */
+    pub fn segment_box(&self, index: usize) -> Option<BoundingBox<f64>> {
+        return self.boxes.get(index).copied();
+    }
+
+    /**
+    Returns the bounding box of the whole path or `None` if the path is empty.
+
+    In contrast to [`ToBoundingBox::bounding_box`], this does not panic for
+    empty paths.
+     */
+    pub fn bounds(&self) -> Option<BoundingBox<f64>> {
+        return self.bounds;
+    }
+
+    /**
+    Appends a segment to the path and returns its index.
+
+    The bounding box of the path is updated by uniting it with the bounding box
+    of the new segment.
+
+    # Panics
+    Panics if the bounding box of the segment can't be computed, e.g. because
+    it contains NaN values.
+     */
+    pub fn push<S: Into<PathSegment>>(&mut self, segment: S) -> usize {
+        let segment: PathSegment = segment.into();
+        let bb = segment.bounding_box();
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.union(&bb),
+            None => bb,
+        });
+        self.segments.push(segment);
+        self.boxes.push(bb);
+        return self.segments.len() - 1;
+    }
+
+    /**
+    Removes the last segment and returns it or `None` if the path is empty.
+     */
+    pub fn pop(&mut self) -> Option<PathSegment> {
+        let segment = self.segments.pop()?;
+        let bb = self.boxes.pop().expect("one box per segment");
+        self.shrink_bounds(&bb);
+        return Some(segment);
+    }
+
+    /**
+    Replaces the segment with the given index and returns the old segment.
+
+    Only the bounding box of the replaced segment is recalculated. If the old
+    segment touched the boundary of the path bounding box, the path bounding
+    box is recomputed from the cached segment boxes.
+
+    # Panics
+    Panics if `index` is out of bounds or if the bounding box of the segment
+    can't be computed, e.g. because it contains NaN values.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, LineSegment, Path, ToBoundingBox};
+
+    let mut path = Path::new();
+    path.push(LineSegment::new([0.0, 0.0], [1.0, 0.0]));
+    path.push(LineSegment::new([1.0, 0.0], [5.0, 5.0]));
+
+    path.set(1, LineSegment::new([1.0, 0.0], [1.0, 1.0]));
+    assert_eq!(path.bounding_box(), BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    ```
+     */
+    pub fn set<S: Into<PathSegment>>(&mut self, index: usize, segment: S) -> PathSegment {
+        let segment: PathSegment = segment.into();
+        let bb = segment.bounding_box();
+        let old_bb = std::mem::replace(&mut self.boxes[index], bb);
+        self.update_bounds(&old_bb, &bb);
+        return std::mem::replace(&mut self.segments[index], segment);
+    }
+
+    /**
+    Translates the segment with the given index by `shift`.
+
+    The cached bounding box of the segment is translated as well, so no curve
+    needs to be evaluated.
+
+    # Panics
+    Panics if `index` is out of bounds.
+     */
+    pub fn translate_segment<P: Into<[f64; 2]>>(&mut self, index: usize, shift: P) {
+        let shift: [f64; 2] = shift.into();
+        self.segments[index].translate(shift);
+        let old_bb = self.boxes[index];
+        self.boxes[index].translate(shift);
+        let bb = self.boxes[index];
+        self.update_bounds(&old_bb, &bb);
+    }
+
+    /**
+    Translates the whole path by `shift`, including all cached boxes.
+     */
+    pub fn translate<P: Into<[f64; 2]>>(&mut self, shift: P) {
+        let shift: [f64; 2] = shift.into();
+        for (segment, bb) in self.segments.iter_mut().zip(self.boxes.iter_mut()) {
+            segment.translate(shift);
+            bb.translate(shift);
+        }
+        if let Some(bounds) = self.bounds.as_mut() {
+            bounds.translate(shift);
+        }
+    }
+
+    /// Updates the path bounding box after the box of a segment changed from
+    /// `old_bb` to `new_bb`.
+    fn update_bounds(&mut self, old_bb: &BoundingBox<f64>, new_bb: &BoundingBox<f64>) {
+        if !new_bb.covers(old_bb) {
+            self.shrink_bounds(old_bb);
+        }
+        self.bounds = self.bounds.map(|bounds| bounds.union(new_bb));
+    }
+
+    /// Recomputes the path bounding box from the cached boxes if the removed
+    /// box `old_bb` touched its boundary.
+    fn shrink_bounds(&mut self, old_bb: &BoundingBox<f64>) {
+        if let Some(bounds) = self.bounds {
+            let inner = bounds.xmin() < old_bb.xmin()
+                && old_bb.xmax() < bounds.xmax()
+                && bounds.ymin() < old_bb.ymin()
+                && old_bb.ymax() < bounds.ymax();
+            if !inner {
+                self.bounds = BoundingBox::from_bounded_entities(self.boxes.iter());
+            }
+        }
+    }
+
+    /**
+    Returns the indices and segments whose cached bounding box
+    [intersects](BoundingBox::intersects) `query`.
+
+    Note that the segment itself does not necessarily intersect `query`, only
+    its bounding box does.
+     */
+    pub fn query_intersecting<'a>(
+        &'a self,
+        query: &'a BoundingBox<f64>,
+    ) -> impl Iterator<Item = (usize, &'a PathSegment)> + 'a {
+        return self
+            .boxes
+            .iter()
+            .zip(self.segments.iter())
+            .enumerate()
+            .filter(|(_, (bb, _))| bb.intersects(query))
+            .map(|(idx, (_, segment))| (idx, segment));
+    }
+}
+
+impl ToBoundingBox for Path {
+    /**
+    Returns the cached bounding box of the whole path.
+
+    # Panics
+    Panics if the path is empty. Use [`Path::bounds`] for a non-panicking
+    variant.
+     */
+    fn bounding_box(&self) -> BoundingBox<f64> {
+        return self.bounds.expect("path must not be empty");
+    }
+}
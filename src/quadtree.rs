@@ -0,0 +1,466 @@
+/*!
+Defines the [`QuadTree`] spatial index for dynamic sets of items implementing
+[`ToBoundingBox`](crate::ToBoundingBox).
+ */
+
+use crate::{BoundingBox, Scalar, ToBoundingBox};
+
+/// Number of items a node can hold before it is subdivided.
+const NODE_CAPACITY: usize = 8;
+
+/// Maximum depth of the tree. Nodes at this depth are never subdivided.
+const MAX_DEPTH: usize = 16;
+
+/**
+Handle of an item stored in a [`QuadTree`].
+
+A key is returned by [`QuadTree::insert`] and stays valid until the item is
+[removed](QuadTree::remove). Afterwards, the key may be reused for another
+item.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct QuadTreeKey(usize);
+
+/**
+A region [quadtree](https://en.wikipedia.org/wiki/Quadtree) spatial index over
+items implementing [`ToBoundingBox`].
+
+The tree covers a fixed root [`BoundingBox`]. Each node which holds too many
+items is subdivided into four quadrants at its
+[`center`](BoundingBox::center). An item is stored in the deepest node whose
+region [covers](BoundingBox::covers) the bounding box of the item, which means
+that items straddling a quadrant boundary stay in the parent node. Items which
+are not covered by the root box are kept in the root node, so they are still
+found by all queries, albeit less efficiently.
+
+In contrast to the [`RTree`](crate::RTree), the structure of a quadtree does
+not depend on the insertion order, and insertions, removals and
+[updates](QuadTree::update) only touch the nodes along a single path from the
+root. This makes it well suited for interactive applications where items are
+frequently inserted, moved and deleted.
+
+Each item is identified by a [`QuadTreeKey`] which is returned on insertion.
+The bounding box of an item is cached; if the geometry of an item changes (e.g.
+via [`get_mut`](QuadTree::get_mut)), [`update`](QuadTree::update) needs to be
+called to move it to the correct node.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, QuadTree, ToBoundingBox};
+
+struct Circle {
+    center: [f64; 2],
+    radius: f64
+}
+
+impl ToBoundingBox for Circle {
+    fn bounding_box(&self) -> BoundingBox {
+        return BoundingBox::new(self.center[0] - self.radius,
+                                self.center[0] + self.radius,
+                                self.center[1] - self.radius,
+                                self.center[1] + self.radius);
+    }
+}
+
+let mut tree = QuadTree::new(BoundingBox::new(0.0, 100.0, 0.0, 100.0));
+let c1 = tree.insert(Circle {center: [10.0, 10.0], radius: 1.0});
+let c2 = tree.insert(Circle {center: [80.0, 20.0], radius: 2.0});
+
+let found = tree.query_intersecting(&BoundingBox::new(0.0, 50.0, 0.0, 50.0));
+assert_eq!(found.len(), 1);
+assert_eq!(found[0].0, c1);
+
+// Move the second circle and update the tree
+tree.get_mut(c2).unwrap().center = [20.0, 20.0];
+tree.update(c2);
+assert_eq!(tree.query_intersecting(&BoundingBox::new(0.0, 50.0, 0.0, 50.0)).len(), 2);
+
+// Find all circles within a radius around a point
+let found = tree.query_radius([15.0, 15.0], 6.0);
+assert_eq!(found.len(), 2);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct QuadTree<T, S = f64> {
+    root: Node<S>,
+    slots: Vec<Option<Slot<T, S>>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Slot<T, S> {
+    bb: BoundingBox<S>,
+    item: T,
+}
+
+#[derive(Debug, Clone)]
+struct Node<S> {
+    region: BoundingBox<S>,
+    items: Vec<usize>,
+    children: Option<Box<[Node<S>; 4]>>,
+}
+
+impl<S: Scalar> Node<S> {
+    fn new(region: BoundingBox<S>) -> Self {
+        return Node {
+            region,
+            items: Vec::new(),
+            children: None,
+        };
+    }
+
+    fn subdivide(&mut self) {
+        let [xc, yc] = self.region.center();
+        let r = &self.region;
+        self.children = Some(Box::new([
+            Node::new(BoundingBox::new(r.xmin(), xc, r.ymin(), yc)),
+            Node::new(BoundingBox::new(xc, r.xmax(), r.ymin(), yc)),
+            Node::new(BoundingBox::new(r.xmin(), xc, yc, r.ymax())),
+            Node::new(BoundingBox::new(xc, r.xmax(), yc, r.ymax())),
+        ]));
+    }
+
+    /// Returns the index of the child covering `bb`, if any.
+    fn child_index(&self, bb: &BoundingBox<S>) -> Option<usize> {
+        return self
+            .children
+            .as_ref()?
+            .iter()
+            .position(|child| child.region.covers(bb));
+    }
+
+    fn insert<T>(&mut self, id: usize, slots: &[Option<Slot<T, S>>], depth: usize) {
+        let bb = slot_bb(slots, id);
+        if let Some(idx) = self.child_index(&bb) {
+            let children = self.children.as_mut().expect("node has children");
+            children[idx].insert(id, slots, depth + 1);
+            return;
+        }
+        self.items.push(id);
+        if self.children.is_none() && self.items.len() > NODE_CAPACITY && depth < MAX_DEPTH {
+            self.subdivide();
+            let items = std::mem::take(&mut self.items);
+            for id in items {
+                let bb = slot_bb(slots, id);
+                match self.child_index(&bb) {
+                    Some(idx) => {
+                        let children = self.children.as_mut().expect("node has children");
+                        children[idx].insert(id, slots, depth + 1);
+                    }
+                    None => self.items.push(id),
+                }
+            }
+        }
+    }
+
+    /// Removes `id` (whose cached bounding box is `bb`) from the subtree and
+    /// merges nodes which became sparse. Returns true if the id was found.
+    fn remove(&mut self, id: usize, bb: &BoundingBox<S>) -> bool {
+        if let Some(pos) = self.items.iter().position(|other| *other == id) {
+            self.items.swap_remove(pos);
+            return true;
+        }
+        let idx = match self.child_index(bb) {
+            Some(idx) => idx,
+            None => return false,
+        };
+        let children = self.children.as_mut().expect("node has children");
+        if !children[idx].remove(id, bb) {
+            return false;
+        }
+
+        // Merge the children back into this node if they are sparse leaves
+        let is_sparse = children.iter().all(|child| child.children.is_none())
+            && self.items.len() + children.iter().map(|c| c.items.len()).sum::<usize>()
+                <= NODE_CAPACITY;
+        if is_sparse {
+            let children = self.children.take().expect("node has children");
+            for child in children.into_iter() {
+                self.items.extend(child.items);
+            }
+        }
+        return true;
+    }
+
+    fn select<'a, T>(
+        &self,
+        node_pred: &impl Fn(&BoundingBox<S>) -> bool,
+        item_pred: &impl Fn(&BoundingBox<S>) -> bool,
+        slots: &'a [Option<Slot<T, S>>],
+        out: &mut Vec<(QuadTreeKey, &'a T)>,
+    ) {
+        for id in self.items.iter() {
+            let slot = slots[*id].as_ref().expect("stored ids are valid");
+            if item_pred(&slot.bb) {
+                out.push((QuadTreeKey(*id), &slot.item));
+            }
+        }
+        if let Some(children) = self.children.as_ref() {
+            for child in children.iter() {
+                if node_pred(&child.region) {
+                    child.select(node_pred, item_pred, slots, out);
+                }
+            }
+        }
+    }
+}
+
+fn slot_bb<T, S: Scalar>(slots: &[Option<Slot<T, S>>], id: usize) -> BoundingBox<S> {
+    return slots[id].as_ref().expect("stored ids are valid").bb;
+}
+
+impl<T: ToBoundingBox<S>, S: Scalar> QuadTree<T, S> {
+    /**
+    Creates an empty quadtree covering the region `root`.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, QuadTree};
+
+    let tree: QuadTree<BoundingBox> = QuadTree::new(BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    assert!(tree.is_empty());
+    ```
+     */
+    pub fn new(root: BoundingBox<S>) -> Self {
+        return QuadTree {
+            root: Node::new(root),
+            slots: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+        };
+    }
+
+    /**
+    Returns the region covered by the root node of the tree.
+     */
+    pub fn region(&self) -> BoundingBox<S> {
+        return self.root.region;
+    }
+
+    /**
+    Returns the number of items stored in the tree.
+     */
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    /**
+    Returns true if the tree does not contain any items.
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /**
+    Inserts an item into the tree and returns its key.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, QuadTree};
+
+    let mut tree = QuadTree::new(BoundingBox::new(0.0, 10.0, 0.0, 10.0));
+    let key = tree.insert(BoundingBox::new(1.0, 2.0, 1.0, 2.0));
+    assert_eq!(tree.get(key), Some(&BoundingBox::new(1.0, 2.0, 1.0, 2.0)));
+    ```
+     */
+    pub fn insert(&mut self, item: T) -> QuadTreeKey {
+        let slot = Slot {
+            bb: item.bounding_box(),
+            item,
+        };
+        let id = match self.free.pop() {
+            Some(id) => {
+                self.slots[id] = Some(slot);
+                id
+            }
+            None => {
+                self.slots.push(Some(slot));
+                self.slots.len() - 1
+            }
+        };
+        self.root.insert(id, &self.slots, 0);
+        self.len += 1;
+        return QuadTreeKey(id);
+    }
+
+    /**
+    Removes the item with the given key from the tree and returns it. If the
+    key is not valid, `None` is returned.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, QuadTree};
+
+    let mut tree = QuadTree::new(BoundingBox::new(0.0, 10.0, 0.0, 10.0));
+    let key = tree.insert(BoundingBox::new(1.0, 2.0, 1.0, 2.0));
+    assert!(tree.remove(key).is_some());
+    assert!(tree.remove(key).is_none());
+    assert!(tree.is_empty());
+    ```
+     */
+    pub fn remove(&mut self, key: QuadTreeKey) -> Option<T> {
+        let bb = self.slots.get(key.0)?.as_ref()?.bb;
+        self.root.remove(key.0, &bb);
+        self.free.push(key.0);
+        self.len -= 1;
+        return self.slots[key.0].take().map(|slot| slot.item);
+    }
+
+    /**
+    Returns a reference to the item with the given key.
+     */
+    pub fn get(&self, key: QuadTreeKey) -> Option<&T> {
+        return self.slots.get(key.0)?.as_ref().map(|slot| &slot.item);
+    }
+
+    /**
+    Returns a mutable reference to the item with the given key.
+
+    If the bounding box of the item is changed via this reference,
+    [`QuadTree::update`] must be called afterwards. Otherwise, queries use the
+    outdated bounding box.
+     */
+    pub fn get_mut(&mut self, key: QuadTreeKey) -> Option<&mut T> {
+        return self
+            .slots
+            .get_mut(key.0)?
+            .as_mut()
+            .map(|slot| &mut slot.item);
+    }
+
+    /**
+    Recalculates the bounding box of the item with the given key and moves it
+    to the matching node if the box has changed. Returns false if the key is
+    not valid.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, QuadTree};
+
+    let mut tree = QuadTree::new(BoundingBox::new(0.0, 10.0, 0.0, 10.0));
+    let key = tree.insert(BoundingBox::new(1.0, 2.0, 1.0, 2.0));
+
+    tree.get_mut(key).unwrap().translate([5.0, 5.0]);
+    assert!(tree.update(key));
+    assert_eq!(tree.query_point([6.5, 6.5]).len(), 1);
+    assert_eq!(tree.query_point([1.5, 1.5]).len(), 0);
+    ```
+     */
+    pub fn update(&mut self, key: QuadTreeKey) -> bool {
+        let slot = match self.slots.get_mut(key.0) {
+            Some(Some(slot)) => slot,
+            _ => return false,
+        };
+        let old_bb = slot.bb;
+        let new_bb = slot.item.bounding_box();
+        if old_bb == new_bb {
+            return true;
+        }
+        self.root.remove(key.0, &old_bb);
+        self.slots[key.0].as_mut().expect("slot exists").bb = new_bb;
+        self.root.insert(key.0, &self.slots, 0);
+        return true;
+    }
+
+    /**
+    Returns an iterator over all keys and items stored in the tree.
+     */
+    pub fn iter(&self) -> impl Iterator<Item = (QuadTreeKey, &T)> {
+        return self
+            .slots
+            .iter()
+            .enumerate()
+            .filter_map(|(id, slot)| slot.as_ref().map(|slot| (QuadTreeKey(id), &slot.item)));
+    }
+
+    fn select(
+        &self,
+        node_pred: impl Fn(&BoundingBox<S>) -> bool,
+        item_pred: impl Fn(&BoundingBox<S>) -> bool,
+    ) -> Vec<(QuadTreeKey, &T)> {
+        let mut out = Vec::new();
+        self.root
+            .select(&node_pred, &item_pred, &self.slots, &mut out);
+        return out;
+    }
+
+    /**
+    Returns the keys and items whose bounding box
+    [intersects](BoundingBox::intersects) `query`.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, QuadTree};
+
+    let mut tree = QuadTree::new(BoundingBox::new(0.0, 10.0, 0.0, 10.0));
+    tree.insert(BoundingBox::new(1.0, 2.0, 1.0, 2.0));
+    tree.insert(BoundingBox::new(4.0, 6.0, 4.0, 6.0));
+    tree.insert(BoundingBox::new(8.0, 9.0, 8.0, 9.0));
+
+    let found = tree.query_intersecting(&BoundingBox::new(0.0, 5.0, 0.0, 5.0));
+    assert_eq!(found.len(), 2);
+    ```
+     */
+    pub fn query_intersecting(&self, query: &BoundingBox<S>) -> Vec<(QuadTreeKey, &T)> {
+        return self.select(|bb| bb.intersects(query), |bb| bb.intersects(query));
+    }
+
+    /**
+    Returns the keys and items whose bounding box
+    [covers](BoundingBox::covers_point) the given point.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, QuadTree};
+
+    let mut tree = QuadTree::new(BoundingBox::new(0.0, 10.0, 0.0, 10.0));
+    tree.insert(BoundingBox::new(1.0, 2.0, 1.0, 2.0));
+    tree.insert(BoundingBox::new(2.0, 3.0, 1.0, 2.0));
+
+    assert_eq!(tree.query_point([1.5, 1.5]).len(), 1);
+    assert_eq!(tree.query_point([2.0, 1.5]).len(), 2);
+    ```
+     */
+    pub fn query_point<P: Into<[S; 2]>>(&self, point: P) -> Vec<(QuadTreeKey, &T)> {
+        let point: [S; 2] = point.into();
+        return self.select(|bb| bb.covers_point(point), |bb| bb.covers_point(point));
+    }
+
+    /**
+    Returns the keys and items whose bounding box is within the distance
+    `radius` of the given point, i.e. whose
+    [distance](BoundingBox::distance_to_point) to the point is smaller than or
+    equal to `radius`. A negative radius results in an empty vector.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, QuadTree};
+
+    let mut tree = QuadTree::new(BoundingBox::new(0.0, 10.0, 0.0, 10.0));
+    tree.insert(BoundingBox::new(1.0, 2.0, 1.0, 2.0));
+    tree.insert(BoundingBox::new(4.0, 5.0, 1.0, 2.0));
+
+    assert_eq!(tree.query_radius([3.0, 1.5], 0.5).len(), 0);
+    assert_eq!(tree.query_radius([3.0, 1.5], 1.0).len(), 2);
+    assert_eq!(tree.query_radius([1.5, 1.5], -1.0).len(), 0);
+    ```
+     */
+    pub fn query_radius<P: Into<[S; 2]>>(&self, point: P, radius: S) -> Vec<(QuadTreeKey, &T)> {
+        if radius < S::ZERO {
+            return Vec::new();
+        }
+        let point: [S; 2] = point.into();
+        let squared_radius = radius * radius;
+        let within = |bb: &BoundingBox<S>| bb.squared_distance_to_point(point) <= squared_radius;
+        return self.select(within, within);
+    }
+}
@@ -0,0 +1,268 @@
+/*!
+Ray and line segment intersection tests for [`BoundingBox`] based on the
+[slab method](https://en.wikipedia.org/wiki/Slab_method).
+ */
+
+use crate::BoundingBox;
+
+/**
+One of the four sides of a [`BoundingBox`].
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoxSide {
+    /// The side at `xmin`.
+    Left,
+    /// The side at `xmax`.
+    Right,
+    /// The side at `ymin`.
+    Bottom,
+    /// The side at `ymax`.
+    Top,
+}
+
+impl BoxSide {
+    fn from_axis(axis: usize, direction: f64, entering: bool) -> Self {
+        // A ray moving in positive direction enters through the min side
+        let min_side = (direction > 0.0) == entering;
+        match (axis, min_side) {
+            (0, true) => return BoxSide::Left,
+            (0, false) => return BoxSide::Right,
+            (_, true) => return BoxSide::Bottom,
+            (_, false) => return BoxSide::Top,
+        }
+    }
+}
+
+/**
+Detailed result of a ray or segment intersection test, see
+[`BoundingBox::ray_hit`] and [`BoundingBox::segment_hit`].
+
+Points on the ray / segment are given by `origin + t * direction`.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    /// Parameter at which the ray / segment enters the box.
+    pub t_enter: f64,
+    /// Parameter at which the ray / segment exits the box. This is infinite if
+    /// a ray never leaves an infinite box.
+    pub t_exit: f64,
+    /// Point at which the ray / segment enters the box.
+    pub entry: [f64; 2],
+    /// Point at which the ray / segment exits the box or `None` if `t_exit` is
+    /// infinite.
+    pub exit: Option<[f64; 2]>,
+    /// Side through which the ray / segment enters the box. This is `None` if
+    /// the origin (start point) is covered by the box.
+    pub entry_side: Option<BoxSide>,
+    /// Side through which the ray / segment exits the box. This is `None` if
+    /// the ray never leaves an infinite box or if the end point of the segment
+    /// is covered by the box.
+    pub exit_side: Option<BoxSide>,
+}
+
+impl BoundingBox<f64> {
+    /**
+    Returns the parameters `(t_enter, t_exit)` at which the ray starting at
+    `origin` and pointing in `direction` enters and exits the box or `None` if
+    the ray misses the box.
+
+    Points on the ray are given by `origin + t * direction` with `t >= 0`. If
+    the origin is covered by the box, `t_enter` is zero. The box is treated as
+    closed, so a ray which only touches an edge or corner is a hit with
+    `t_enter == t_exit`. Zero components of `direction` are handled explicitly
+    (the ray then runs parallel to the respective sides) and infinite boxes
+    are supported, in which case `t_exit` may be infinite. `origin` and
+    `direction` are expected to be finite.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(2.0, 4.0, -1.0, 1.0);
+    assert_eq!(bb.ray_intersection([0.0, 0.0], [1.0, 0.0]), Some((2.0, 4.0)));
+    assert_eq!(bb.ray_intersection([0.0, 0.0], [2.0, 0.0]), Some((1.0, 2.0)));
+    assert_eq!(bb.ray_intersection([3.0, 0.0], [1.0, 0.0]), Some((0.0, 1.0)));
+
+    // Ray points away from the box or runs parallel to it
+    assert_eq!(bb.ray_intersection([0.0, 0.0], [-1.0, 0.0]), None);
+    assert_eq!(bb.ray_intersection([0.0, 2.0], [1.0, 0.0]), None);
+
+    // Infinite box
+    let bb = BoundingBox::new(0.0, f64::INFINITY, 0.0, 1.0);
+    assert_eq!(bb.ray_intersection([-1.0, 0.5], [1.0, 0.0]), Some((1.0, f64::INFINITY)));
+    ```
+     */
+    pub fn ray_intersection<P: Into<[f64; 2]>, D: Into<[f64; 2]>>(
+        &self,
+        origin: P,
+        direction: D,
+    ) -> Option<(f64, f64)> {
+        return self
+            .ray_hit(origin, direction)
+            .map(|hit| (hit.t_enter, hit.t_exit));
+    }
+
+    /**
+    Returns the parameters `(t_enter, t_exit)` at which the line segment from
+    `start` to `end` enters and exits the box or `None` if the segment misses
+    the box.
+
+    Points on the segment are given by `start + t * (end - start)` with
+    `0 <= t <= 1`. If `start` is covered by the box, `t_enter` is zero, and if
+    `end` is covered by the box, `t_exit` is one. See
+    [`BoundingBox::ray_intersection`] for details.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    assert_eq!(bb.segment_intersection([-1.0, 0.5], [3.0, 0.5]), Some((0.25, 0.5)));
+    assert_eq!(bb.segment_intersection([0.5, 0.5], [0.5, 0.75]), Some((0.0, 1.0)));
+
+    // Segment ends before it reaches the box
+    assert_eq!(bb.segment_intersection([-2.0, 0.5], [-1.0, 0.5]), None);
+    ```
+     */
+    pub fn segment_intersection<P: Into<[f64; 2]>, Q: Into<[f64; 2]>>(
+        &self,
+        start: P,
+        end: Q,
+    ) -> Option<(f64, f64)> {
+        return self
+            .segment_hit(start, end)
+            .map(|hit| (hit.t_enter, hit.t_exit));
+    }
+
+    /**
+    Like [`BoundingBox::ray_intersection`], but additionally returns the entry
+    and exit points as well as the sides through which the ray enters and
+    exits the box.
+
+    If the ray hits a corner, the side of the x-dimension is reported.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, BoxSide};
+
+    let bb = BoundingBox::new(0.0, 2.0, 0.0, 2.0);
+    let hit = bb.ray_hit([-1.0, 0.0], [1.0, 1.0]).unwrap();
+    assert_eq!(hit.t_enter, 1.0);
+    assert_eq!(hit.entry, [0.0, 1.0]);
+    assert_eq!(hit.entry_side, Some(BoxSide::Left));
+    assert_eq!(hit.exit, Some([1.0, 2.0]));
+    assert_eq!(hit.exit_side, Some(BoxSide::Top));
+
+    // Origin inside the box
+    let hit = bb.ray_hit([1.0, 1.0], [0.0, -1.0]).unwrap();
+    assert_eq!(hit.entry_side, None);
+    assert_eq!(hit.exit_side, Some(BoxSide::Bottom));
+    ```
+     */
+    pub fn ray_hit<P: Into<[f64; 2]>, D: Into<[f64; 2]>>(
+        &self,
+        origin: P,
+        direction: D,
+    ) -> Option<RayHit> {
+        return self.clip_line(origin.into(), direction.into(), f64::INFINITY);
+    }
+
+    /**
+    Like [`BoundingBox::segment_intersection`], but additionally returns the
+    entry and exit points as well as the sides through which the segment enters
+    and exits the box.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, BoxSide};
+
+    let bb = BoundingBox::new(0.0, 2.0, 0.0, 2.0);
+    let hit = bb.segment_hit([1.0, 3.0], [1.0, 1.0]).unwrap();
+    assert_eq!(hit.entry, [1.0, 2.0]);
+    assert_eq!(hit.entry_side, Some(BoxSide::Top));
+    assert_eq!(hit.exit, Some([1.0, 1.0]));
+    assert_eq!(hit.exit_side, None);
+    ```
+     */
+    pub fn segment_hit<P: Into<[f64; 2]>, Q: Into<[f64; 2]>>(
+        &self,
+        start: P,
+        end: Q,
+    ) -> Option<RayHit> {
+        let start: [f64; 2] = start.into();
+        let end: [f64; 2] = end.into();
+        return self.clip_line(start, [end[0] - start[0], end[1] - start[1]], 1.0);
+    }
+
+    /// Clips the line `origin + t * direction` with `0 <= t <= t_max` to the
+    /// box using the slab method.
+    fn clip_line(&self, origin: [f64; 2], direction: [f64; 2], t_max: f64) -> Option<RayHit> {
+        let mins = [self.xmin(), self.ymin()];
+        let maxs = [self.xmax(), self.ymax()];
+        let mut t_enter = 0.0;
+        let mut t_exit = t_max;
+        let mut entry_side = None;
+        let mut exit_side = None;
+        for axis in 0..2 {
+            if direction[axis] == 0.0 {
+                // Parallel to the slab: either always or never inside
+                if !(mins[axis] <= origin[axis] && origin[axis] <= maxs[axis]) {
+                    return None;
+                }
+                continue;
+            }
+            let t1 = (mins[axis] - origin[axis]) / direction[axis];
+            let t2 = (maxs[axis] - origin[axis]) / direction[axis];
+            let (near, far) = if t1 <= t2 { (t1, t2) } else { (t2, t1) };
+            if near > t_enter {
+                t_enter = near;
+                entry_side = Some(BoxSide::from_axis(axis, direction[axis], true));
+            }
+            if far < t_exit {
+                t_exit = far;
+                exit_side = Some(BoxSide::from_axis(axis, direction[axis], false));
+            }
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+        let point = |t: f64| {
+            // Avoid 0 * inf for directions parallel to an axis
+            let coord = |axis: usize| {
+                if direction[axis] == 0.0 {
+                    origin[axis]
+                } else {
+                    origin[axis] + t * direction[axis]
+                }
+            };
+            [coord(0), coord(1)]
+        };
+        // Put the points exactly onto the side they lie on to avoid roundoff
+        let on_side = |mut point: [f64; 2], side: Option<BoxSide>| {
+            match side {
+                Some(BoxSide::Left) => point[0] = self.xmin(),
+                Some(BoxSide::Right) => point[0] = self.xmax(),
+                Some(BoxSide::Bottom) => point[1] = self.ymin(),
+                Some(BoxSide::Top) => point[1] = self.ymax(),
+                None => (),
+            }
+            return point;
+        };
+        return Some(RayHit {
+            t_enter,
+            t_exit,
+            entry: on_side(point(t_enter), entry_side),
+            exit: if t_exit.is_finite() {
+                Some(on_side(point(t_exit), exit_side))
+            } else {
+                None
+            },
+            entry_side,
+            exit_side,
+        });
+    }
+}
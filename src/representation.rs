@@ -0,0 +1,287 @@
+/*!
+Validated [serde](https://serde.rs/) deserialization and alternative serde
+representations of [`BoundingBox`].
+
+By default, a [`BoundingBox`] is serialized as a struct with the fields `xmin`,
+`xmax`, `ymin` and `ymax`. When deserializing, the extremas are validated in
+the same way as in [`BoundingBox::new_checked`], so that invalid input (e.g.
+`xmin > xmax` or NaN) results in a descriptive error instead of a box which
+violates the invariants of this crate:
+
+```
+use bounding_box::BoundingBox;
+
+let bb: BoundingBox = serde_json::from_str(r#"{"xmin": 0.0, "xmax": 1.0, "ymin": 0.0, "ymax": 2.0}"#).unwrap();
+assert_eq!(bb, BoundingBox::new(0.0, 1.0, 0.0, 2.0));
+
+let err = serde_json::from_str::<BoundingBox>(r#"{"xmin": 2.0, "xmax": 1.0, "ymin": 0.0, "ymax": 2.0}"#).unwrap_err();
+assert!(err.to_string().contains("xmin (2) is larger than xmax (1)"));
+```
+
+The submodules of this module provide alternative representations, which can
+be selected with the `#[serde(with = "...")]` field attribute:
+
+| Module                 | Representation                              |
+|------------------------|---------------------------------------------|
+| [`array`](mod@array)   | `[xmin, ymin, xmax, ymax]`                  |
+| [`corners`]            | `{"min": [xmin, ymin], "max": [xmax, ymax]}` |
+| [`xywh`]               | `{"x": xmin, "y": ymin, "width": .., "height": ..}` |
+
+All of them validate the input during deserialization as well. The
+three-dimensional [`BoundingBox3`] is validated like
+[`BoundingBox3::try_new`] when deserializing, but has no alternative
+representations.
+
+# Examples
+
+```
+use bounding_box::BoundingBox;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Layout {
+    #[serde(with = "bounding_box::representation::array")]
+    frame: BoundingBox,
+    #[serde(with = "bounding_box::representation::corners")]
+    content: BoundingBox,
+    #[serde(with = "bounding_box::representation::xywh")]
+    logo: BoundingBox,
+}
+
+let layout = Layout {
+    frame: BoundingBox::new(0.0, 10.0, 0.0, 5.0),
+    content: BoundingBox::new(1.0, 9.0, 1.0, 4.0),
+    logo: BoundingBox::new(1.0, 3.0, 1.0, 2.0),
+};
+let json = serde_json::to_string(&layout).unwrap();
+assert_eq!(
+    json,
+    concat!(
+        r#"{"frame":[0.0,0.0,10.0,5.0],"#,
+        r#""content":{"min":[1.0,1.0],"max":[9.0,4.0]},"#,
+        r#""logo":{"x":1.0,"y":1.0,"width":2.0,"height":1.0}}"#
+    )
+);
+
+let parsed: Layout = serde_json::from_str(&json).unwrap();
+assert_eq!(parsed.frame, layout.frame);
+assert_eq!(parsed.content, layout.content);
+assert_eq!(parsed.logo, layout.logo);
+```
+
+# Features
+
+This module is only available if the `serde` feature is enabled.
+ */
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+
+use crate::{BoundingBox, BoundingBox3, Scalar};
+
+/// Creates a bounding box via [`BoundingBox::new_checked`] and converts a
+/// [`BoundingBoxError`](crate::BoundingBoxError) into a deserialization error.
+fn validated<T: Scalar, E: Error>(xmin: T, xmax: T, ymin: T, ymax: T) -> Result<BoundingBox<T>, E> {
+    return BoundingBox::new_checked(xmin, xmax, ymin, ymax).map_err(E::custom);
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for BoundingBox<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "BoundingBox")]
+        struct Extremas<T> {
+            xmin: T,
+            xmax: T,
+            ymin: T,
+            ymax: T,
+        }
+
+        let e = Extremas::deserialize(deserializer)?;
+        return validated(e.xmin, e.xmax, e.ymin, e.ymax);
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for BoundingBox3<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "BoundingBox3")]
+        struct Extremas<T> {
+            xmin: T,
+            xmax: T,
+            ymin: T,
+            ymax: T,
+            zmin: T,
+            zmax: T,
+        }
+
+        let e = Extremas::deserialize(deserializer)?;
+        let bb = BoundingBox3::try_new(e.xmin, e.xmax, e.ymin, e.ymax, e.zmin, e.zmax);
+        return bb.ok_or_else(|| {
+            D::Error::custom(
+                "invalid bounding box: one of the conditions xmin <= xmax, ymin <= ymax and zmin <= zmax is not fulfilled or an extremum is NaN",
+            )
+        });
+    }
+}
+
+/**
+(De)serializes a [`BoundingBox`] as an array `[xmin, ymin, xmax, ymax]`.
+
+This is the layout used by e.g. GeoJSON and many image annotation formats. See
+the [module documentation](crate::representation) for an example.
+ */
+pub mod array {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{BoundingBox, Scalar};
+
+    /**
+    Serializes `bb` as `[xmin, ymin, xmax, ymax]`.
+     */
+    pub fn serialize<T: Scalar + Serialize, S: Serializer>(
+        bb: &BoundingBox<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        return [bb.xmin(), bb.ymin(), bb.xmax(), bb.ymax()].serialize(serializer);
+    }
+
+    /**
+    Deserializes a bounding box from `[xmin, ymin, xmax, ymax]`.
+     */
+    pub fn deserialize<'de, T: Scalar + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BoundingBox<T>, D::Error> {
+        let [xmin, ymin, xmax, ymax] = <[T; 4]>::deserialize(deserializer)?;
+        return super::validated(xmin, xmax, ymin, ymax);
+    }
+}
+
+/**
+(De)serializes a [`BoundingBox`] by its corners as
+`{"min": [xmin, ymin], "max": [xmax, ymax]}`.
+
+See the [module documentation](crate::representation) for an example.
+ */
+pub mod corners {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{BoundingBox, Scalar};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "BoundingBox")]
+    struct Corners<T> {
+        min: [T; 2],
+        max: [T; 2],
+    }
+
+    /**
+    Serializes `bb` as `{"min": [xmin, ymin], "max": [xmax, ymax]}`.
+     */
+    pub fn serialize<T: Scalar + Serialize, S: Serializer>(
+        bb: &BoundingBox<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let corners = Corners {
+            min: [bb.xmin(), bb.ymin()],
+            max: [bb.xmax(), bb.ymax()],
+        };
+        return corners.serialize(serializer);
+    }
+
+    /**
+    Deserializes a bounding box from `{"min": [xmin, ymin], "max": [xmax, ymax]}`.
+     */
+    pub fn deserialize<'de, T: Scalar + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BoundingBox<T>, D::Error> {
+        let c = Corners::<T>::deserialize(deserializer)?;
+        return super::validated(c.min[0], c.max[0], c.min[1], c.max[1]);
+    }
+}
+
+/**
+(De)serializes a [`BoundingBox`] by its minimum corner and size as
+`{"x": xmin, "y": ymin, "width": xmax - xmin, "height": ymax - ymin}`.
+
+This is the layout used by e.g. SVG and many GUI toolkits. Negative widths or
+heights and sizes which overflow the scalar type (see
+[`Scalar::checked_add`]) are rejected during deserialization. Since `xmax` and
+`ymax` are recovered by adding width and height to `xmin` and `ymin`, a
+roundtrip of a floating point box can be subject to roundoff errors. See the
+[module documentation](crate::representation) for an example.
+ */
+pub mod xywh {
+    use serde::de::Error;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{BoundingBox, Scalar};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "BoundingBox")]
+    struct Xywh<T> {
+        x: T,
+        y: T,
+        width: T,
+        height: T,
+    }
+
+    /**
+    Serializes `bb` as `{"x": xmin, "y": ymin, "width": .., "height": ..}`.
+
+    Returns an error if the width or height of `bb` overflows the scalar type
+    (see [`Scalar::checked_sub`]).
+     */
+    pub fn serialize<T: Scalar + Serialize, S: Serializer>(
+        bb: &BoundingBox<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let overflow = |name: &str| {
+            return S::Error::custom(format_args!(
+                "invalid bounding box: {name} overflows the scalar type"
+            ));
+        };
+        let xywh = Xywh {
+            x: bb.xmin(),
+            y: bb.ymin(),
+            width: bb
+                .xmax()
+                .checked_sub(bb.xmin())
+                .ok_or_else(|| overflow("width"))?,
+            height: bb
+                .ymax()
+                .checked_sub(bb.ymin())
+                .ok_or_else(|| overflow("height"))?,
+        };
+        return xywh.serialize(serializer);
+    }
+
+    /**
+    Deserializes a bounding box from `{"x": xmin, "y": ymin, "width": .., "height": ..}`.
+     */
+    pub fn deserialize<'de, T: Scalar + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BoundingBox<T>, D::Error> {
+        let r = Xywh::<T>::deserialize(deserializer)?;
+        for (name, value) in [("width", r.width), ("height", r.height)] {
+            if value < T::ZERO {
+                return Err(D::Error::custom(format_args!(
+                    "invalid bounding box: {name} ({}) is negative",
+                    value.to_f64()
+                )));
+            }
+        }
+        let overflow = |name: &str| {
+            return D::Error::custom(format_args!(
+                "invalid bounding box: {name} overflows the scalar type"
+            ));
+        };
+        let xmax =
+            r.x.checked_add(r.width)
+                .ok_or_else(|| overflow("x + width"))?;
+        let ymax =
+            r.y.checked_add(r.height)
+                .ok_or_else(|| overflow("y + height"))?;
+        return super::validated(r.x, xmax, r.y, ymax);
+    }
+}
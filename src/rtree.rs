@@ -0,0 +1,889 @@
+/*!
+Defines the [`RTree`] spatial index over types implementing
+[`ToBoundingBox`](crate::ToBoundingBox).
+ */
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::{BoundingBox, Scalar, ToBoundingBox};
+
+/// Maximum number of entries per node.
+const MAX_ENTRIES: usize = 16;
+
+/// Minimum number of entries per node (except for the root).
+const MIN_ENTRIES: usize = 6;
+
+/**
+An [R-tree](https://en.wikipedia.org/wiki/R-tree) spatial index over items
+implementing [`ToBoundingBox`].
+
+An R-tree groups items into a balanced hierarchy of nested bounding boxes.
+Queries only need to descend into the subtrees whose bounding box matches the
+query, which makes e.g. finding all items intersecting a query box roughly
+logarithmic instead of linear in the number of items. A typical use case is
+pre-filtering pairwise intersection tests, which are otherwise quadratic in the
+number of items.
+
+The bounding box of each item is calculated once when it is inserted and then
+cached. If the geometry of an item changes, it has to be
+[removed](RTree::remove) and reinserted.
+
+The tree can be built incrementally with [`insert`](RTree::insert) or much
+more efficiently in one go with one of the bulk loading algorithms
+[`bulk_load`](RTree::bulk_load) (Sort-Tile-Recursive) or
+[`bulk_load_omt`](RTree::bulk_load_omt) (Overlap Minimizing Top-down).
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, RTree, ToBoundingBox};
+
+#[derive(Debug, PartialEq)]
+struct Circle {
+    center: [f64; 2],
+    radius: f64
+}
+
+impl ToBoundingBox for Circle {
+    fn bounding_box(&self) -> BoundingBox {
+        return BoundingBox::new(self.center[0] - self.radius,
+                                self.center[0] + self.radius,
+                                self.center[1] - self.radius,
+                                self.center[1] + self.radius);
+    }
+}
+
+let circles = (0..100).map(|i| Circle {center: [i as f64, 0.0], radius: 0.25});
+let tree = RTree::bulk_load(circles.collect());
+assert_eq!(tree.len(), 100);
+
+// All circles whose bounding box intersects the query window
+let found = tree.query_intersecting(&BoundingBox::new(9.5, 12.0, -1.0, 1.0));
+assert_eq!(found.len(), 3);
+
+// The two circles closest to a point
+let nearest = tree.nearest_neighbors([50.1, 3.0], 2);
+assert_eq!(nearest[0].center, [50.0, 0.0]);
+assert_eq!(nearest[1].center, [51.0, 0.0]);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct RTree<T, S = f64> {
+    root: Option<Node<T, S>>,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<T, S> {
+    bb: BoundingBox<S>,
+    item: T,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T, S> {
+    bb: BoundingBox<S>,
+    children: Children<T, S>,
+}
+
+#[derive(Debug, Clone)]
+enum Children<T, S> {
+    Leaf(Vec<Entry<T, S>>),
+    Internal(Vec<Node<T, S>>),
+}
+
+impl<T, S: Scalar> Node<T, S> {
+    fn leaf(entries: Vec<Entry<T, S>>) -> Self {
+        let bb = union_all(entries.iter().map(|entry| entry.bb));
+        return Node {
+            bb,
+            children: Children::Leaf(entries),
+        };
+    }
+
+    fn internal(nodes: Vec<Node<T, S>>) -> Self {
+        let bb = union_all(nodes.iter().map(|node| node.bb));
+        return Node {
+            bb,
+            children: Children::Internal(nodes),
+        };
+    }
+
+    fn len(&self) -> usize {
+        match &self.children {
+            Children::Leaf(entries) => return entries.len(),
+            Children::Internal(nodes) => return nodes.len(),
+        }
+    }
+
+    fn recompute_bb(&mut self) {
+        match &self.children {
+            Children::Leaf(entries) => {
+                if !entries.is_empty() {
+                    self.bb = union_all(entries.iter().map(|entry| entry.bb));
+                }
+            }
+            Children::Internal(nodes) => {
+                if !nodes.is_empty() {
+                    self.bb = union_all(nodes.iter().map(|node| node.bb));
+                }
+            }
+        }
+    }
+
+    /// Moves all items stored in the subtree into `out`.
+    fn drain_into(self, out: &mut Vec<Entry<T, S>>) {
+        match self.children {
+            Children::Leaf(entries) => out.extend(entries),
+            Children::Internal(nodes) => {
+                for node in nodes {
+                    node.drain_into(out);
+                }
+            }
+        }
+    }
+
+    /// Inserts an entry into the subtree and returns a new sibling node if
+    /// `self` had to be split.
+    fn insert(&mut self, entry: Entry<T, S>) -> Option<Node<T, S>> {
+        self.bb = self.bb.union(&entry.bb);
+        match &mut self.children {
+            Children::Leaf(entries) => {
+                entries.push(entry);
+                if entries.len() > MAX_ENTRIES {
+                    let (left, right) = quadratic_split(std::mem::take(entries), |e| e.bb);
+                    *self = Node::leaf(left);
+                    return Some(Node::leaf(right));
+                }
+            }
+            Children::Internal(nodes) => {
+                let idx = choose_subtree(nodes, &entry.bb);
+                if let Some(sibling) = nodes[idx].insert(entry) {
+                    nodes.push(sibling);
+                    if nodes.len() > MAX_ENTRIES {
+                        let (left, right) = quadratic_split(std::mem::take(nodes), |n| n.bb);
+                        *self = Node::internal(left);
+                        return Some(Node::internal(right));
+                    }
+                }
+            }
+        }
+        return None;
+    }
+
+    /// Removes the first item equal to `item` from the subtree. The items of
+    /// nodes which become underfull are moved into `orphans`.
+    fn remove(&mut self, bb: &BoundingBox<S>, item: &T, orphans: &mut Vec<Entry<T, S>>) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let removed = match &mut self.children {
+            Children::Leaf(entries) => {
+                let idx = entries.iter().position(|entry| entry.item == *item)?;
+                Some(entries.swap_remove(idx).item)
+            }
+            Children::Internal(nodes) => {
+                let mut removed = None;
+                for idx in 0..nodes.len() {
+                    if !nodes[idx].bb.covers(bb) {
+                        continue;
+                    }
+                    if let Some(val) = nodes[idx].remove(bb, item, orphans) {
+                        if nodes[idx].len() < MIN_ENTRIES {
+                            nodes.swap_remove(idx).drain_into(orphans);
+                        }
+                        removed = Some(val);
+                        break;
+                    }
+                }
+                removed
+            }
+        };
+        if removed.is_some() {
+            self.recompute_bb();
+        }
+        return removed;
+    }
+
+    fn select<'a>(
+        &'a self,
+        node_pred: &impl Fn(&BoundingBox<S>) -> bool,
+        item_pred: &impl Fn(&BoundingBox<S>) -> bool,
+        out: &mut Vec<&'a T>,
+    ) {
+        match &self.children {
+            Children::Leaf(entries) => {
+                out.extend(
+                    entries
+                        .iter()
+                        .filter(|entry| item_pred(&entry.bb))
+                        .map(|entry| &entry.item),
+                );
+            }
+            Children::Internal(nodes) => {
+                for node in nodes.iter().filter(|node| node_pred(&node.bb)) {
+                    node.select(node_pred, item_pred, out);
+                }
+            }
+        }
+    }
+}
+
+impl<T: ToBoundingBox<S>, S: Scalar> RTree<T, S> {
+    /**
+    Creates an empty R-tree.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let tree: RTree<BoundingBox> = RTree::new();
+    assert!(tree.is_empty());
+    ```
+     */
+    pub fn new() -> Self {
+        return RTree { root: None, len: 0 };
+    }
+
+    /**
+    Creates an R-tree from the given items using the Sort-Tile-Recursive (STR)
+    bulk loading algorithm.
+
+    STR sorts the items by the x-coordinate of their bounding box centers,
+    slices them into vertical tiles, sorts each tile by the y-coordinate and
+    packs the result into full leaves. The same procedure is repeated
+    bottom-up for each level of the tree. The resulting tree has a very high
+    node utilization and is built much faster than by repeated
+    [insertion](RTree::insert).
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let boxes: Vec<_> = (0..1000).map(|i| {
+        let x = (i % 100) as f64;
+        let y = (i / 100) as f64;
+        BoundingBox::new(x, x + 0.5, y, y + 0.5)
+    }).collect();
+
+    let tree = RTree::bulk_load(boxes);
+    assert_eq!(tree.len(), 1000);
+    assert_eq!(tree.query_point([10.2, 3.2]).len(), 1);
+    ```
+     */
+    pub fn bulk_load(items: Vec<T>) -> Self {
+        let len = items.len();
+        let entries: Vec<_> = items
+            .into_iter()
+            .map(|item| Entry {
+                bb: item.bounding_box(),
+                item,
+            })
+            .collect();
+        if entries.is_empty() {
+            return RTree::new();
+        }
+        let mut nodes: Vec<Node<T, S>> = str_pack(entries, |e| e.bb)
+            .into_iter()
+            .map(Node::leaf)
+            .collect();
+        while nodes.len() > 1 {
+            nodes = str_pack(nodes, |n| n.bb)
+                .into_iter()
+                .map(Node::internal)
+                .collect();
+        }
+        return RTree {
+            root: nodes.pop(),
+            len,
+        };
+    }
+
+    /**
+    Creates an R-tree from the given items using the Overlap Minimizing
+    Top-down (OMT) bulk loading algorithm.
+
+    OMT first determines the height of the resulting tree and then partitions
+    the items top-down: at each level, the items are sliced along the x-axis
+    and each slice is sliced along the y-axis, so that every resulting group
+    fills exactly one subtree. Compared to [`RTree::bulk_load`], this results in
+    less overlap between the nodes of the upper levels of the tree.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let boxes: Vec<_> = (0..1000).map(|i| {
+        let x = (i % 100) as f64;
+        let y = (i / 100) as f64;
+        BoundingBox::new(x, x + 0.5, y, y + 0.5)
+    }).collect();
+
+    let tree = RTree::bulk_load_omt(boxes);
+    assert_eq!(tree.len(), 1000);
+    assert_eq!(tree.query_point([10.2, 3.2]).len(), 1);
+    ```
+     */
+    pub fn bulk_load_omt(items: Vec<T>) -> Self {
+        let len = items.len();
+        let entries: Vec<_> = items
+            .into_iter()
+            .map(|item| Entry {
+                bb: item.bounding_box(),
+                item,
+            })
+            .collect();
+        if entries.is_empty() {
+            return RTree::new();
+        }
+        let mut height = 1;
+        let mut capacity = MAX_ENTRIES;
+        while capacity < len {
+            height += 1;
+            capacity *= MAX_ENTRIES;
+        }
+        return RTree {
+            root: Some(omt_build(entries, height)),
+            len,
+        };
+    }
+
+    /**
+    Returns the number of items stored in the tree.
+     */
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    /**
+    Returns true if the tree does not contain any items.
+     */
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /**
+    Returns the bounding box of all items stored in the tree or `None` if the
+    tree is empty.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let mut tree = RTree::new();
+    assert!(tree.bounding_box().is_none());
+
+    tree.insert(BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    tree.insert(BoundingBox::new(2.0, 3.0, -1.0, 0.0));
+    assert_eq!(tree.bounding_box(), Some(BoundingBox::new(0.0, 3.0, -1.0, 1.0)));
+    ```
+     */
+    pub fn bounding_box(&self) -> Option<BoundingBox<S>> {
+        return self.root.as_ref().map(|root| root.bb);
+    }
+
+    /**
+    Inserts an item into the tree.
+
+    The item is added to the leaf whose bounding box needs the least
+    enlargement to cover the bounding box of the item. Overflowing nodes are
+    split using the quadratic split algorithm by Guttman.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let mut tree = RTree::new();
+    for i in 0..100 {
+        let x = i as f64;
+        tree.insert(BoundingBox::new(x, x + 1.0, 0.0, 1.0));
+    }
+    assert_eq!(tree.len(), 100);
+    assert_eq!(tree.query_point([10.5, 0.5]).len(), 1);
+    assert_eq!(tree.query_point([10.0, 0.5]).len(), 2);
+    ```
+     */
+    pub fn insert(&mut self, item: T) {
+        let entry = Entry {
+            bb: item.bounding_box(),
+            item,
+        };
+        self.len += 1;
+        self.insert_entry(entry);
+    }
+
+    fn insert_entry(&mut self, entry: Entry<T, S>) {
+        match self.root.as_mut() {
+            Some(root) => {
+                if let Some(sibling) = root.insert(entry) {
+                    let old_root = self.root.take().expect("root exists");
+                    self.root = Some(Node::internal(vec![old_root, sibling]));
+                }
+            }
+            None => self.root = Some(Node::leaf(vec![entry])),
+        }
+    }
+
+    /**
+    Removes the first item equal to `item` from the tree and returns it. If no
+    such item exists, `None` is returned.
+
+    Nodes which become underfull are dissolved and their items are reinserted,
+    which keeps the tree balanced.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let mut tree = RTree::new();
+    tree.insert(BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+    tree.insert(BoundingBox::new(2.0, 3.0, 0.0, 1.0));
+
+    assert!(tree.remove(&BoundingBox::new(0.0, 1.0, 0.0, 1.0)).is_some());
+    assert!(tree.remove(&BoundingBox::new(0.0, 1.0, 0.0, 1.0)).is_none());
+    assert_eq!(tree.len(), 1);
+    ```
+     */
+    pub fn remove(&mut self, item: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let bb = item.bounding_box();
+        let root = self.root.as_mut()?;
+        if !root.bb.covers(&bb) {
+            return None;
+        }
+        let mut orphans = Vec::new();
+        let removed = root.remove(&bb, item, &mut orphans)?;
+        self.len -= 1;
+
+        // Shorten the tree if the root has a single child or no items at all
+        loop {
+            let root = self.root.take().expect("root exists");
+            match root.children {
+                Children::Internal(mut nodes) if nodes.len() == 1 => {
+                    self.root = nodes.pop();
+                }
+                Children::Internal(ref nodes) if nodes.is_empty() => break,
+                Children::Leaf(ref entries) if entries.is_empty() => break,
+                _ => {
+                    self.root = Some(root);
+                    break;
+                }
+            }
+        }
+        for orphan in orphans {
+            self.insert_entry(orphan);
+        }
+        return Some(removed);
+    }
+
+    /**
+    Returns an iterator over all items stored in the tree in no particular
+    order.
+     */
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        return self.select(|_| true, |_| true).into_iter();
+    }
+
+    fn select(
+        &self,
+        node_pred: impl Fn(&BoundingBox<S>) -> bool,
+        item_pred: impl Fn(&BoundingBox<S>) -> bool,
+    ) -> Vec<&T> {
+        let mut out = Vec::new();
+        if let Some(root) = self.root.as_ref()
+            && node_pred(&root.bb)
+        {
+            root.select(&node_pred, &item_pred, &mut out);
+        }
+        return out;
+    }
+
+    /**
+    Returns all items whose bounding box
+    [intersects](BoundingBox::intersects) `query`.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let tree = RTree::bulk_load(vec![
+        BoundingBox::new(0.0, 1.0, 0.0, 1.0),
+        BoundingBox::new(1.0, 2.0, 0.0, 1.0),
+        BoundingBox::new(3.0, 4.0, 0.0, 1.0),
+    ]);
+    let found = tree.query_intersecting(&BoundingBox::new(0.5, 1.0, 0.5, 0.6));
+    assert_eq!(found.len(), 2);
+    ```
+     */
+    pub fn query_intersecting(&self, query: &BoundingBox<S>) -> Vec<&T> {
+        return self.select(|bb| bb.intersects(query), |bb| bb.intersects(query));
+    }
+
+    /**
+    Returns all items whose bounding box is [covered](BoundingBox::covers) by
+    `query`.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let tree = RTree::bulk_load(vec![
+        BoundingBox::new(0.0, 1.0, 0.0, 1.0),
+        BoundingBox::new(1.0, 2.0, 0.0, 1.0),
+        BoundingBox::new(3.0, 4.0, 0.0, 1.0),
+    ]);
+    let found = tree.query_covered_by(&BoundingBox::new(0.0, 2.5, 0.0, 1.0));
+    assert_eq!(found.len(), 2);
+    ```
+     */
+    pub fn query_covered_by(&self, query: &BoundingBox<S>) -> Vec<&T> {
+        return self.select(|bb| bb.intersects(query), |bb| query.covers(bb));
+    }
+
+    /**
+    Returns all items whose bounding box [covers](BoundingBox::covers)
+    `query`.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let tree = RTree::bulk_load(vec![
+        BoundingBox::new(0.0, 1.0, 0.0, 1.0),
+        BoundingBox::new(0.0, 2.0, 0.0, 1.0),
+        BoundingBox::new(3.0, 4.0, 0.0, 1.0),
+    ]);
+    let found = tree.query_covering(&BoundingBox::new(0.5, 1.5, 0.2, 0.8));
+    assert_eq!(found, vec![&BoundingBox::new(0.0, 2.0, 0.0, 1.0)]);
+    ```
+     */
+    pub fn query_covering(&self, query: &BoundingBox<S>) -> Vec<&T> {
+        return self.select(|bb| bb.covers(query), |bb| bb.covers(query));
+    }
+
+    /**
+    Returns all items whose bounding box [covers](BoundingBox::covers_point)
+    the given point.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let tree = RTree::bulk_load(vec![
+        BoundingBox::new(0.0, 1.0, 0.0, 1.0),
+        BoundingBox::new(1.0, 2.0, 0.0, 1.0),
+    ]);
+    assert_eq!(tree.query_point([0.5, 0.5]).len(), 1);
+    assert_eq!(tree.query_point([1.0, 0.5]).len(), 2);
+    assert_eq!(tree.query_point([3.0, 0.5]).len(), 0);
+    ```
+     */
+    pub fn query_point<P: Into<[S; 2]>>(&self, point: P) -> Vec<&T> {
+        let point: [S; 2] = point.into();
+        return self.select(|bb| bb.covers_point(point), |bb| bb.covers_point(point));
+    }
+
+    /**
+    Returns the item whose bounding box is closest to the given point. See
+    [`RTree::nearest_neighbors`].
+     */
+    pub fn nearest_neighbor<P: Into<[S; 2]>>(&self, point: P) -> Option<&T> {
+        return self.nearest_neighbors(point, 1).pop();
+    }
+
+    /**
+    Returns the `k` items whose bounding boxes are closest to the given point,
+    sorted by ascending distance.
+
+    The distance of an item is the
+    [distance](BoundingBox::squared_distance_to_point) between its bounding box
+    and the point, i.e. items whose bounding box covers the point have a
+    distance of zero. The search is performed best-first, which means that only
+    the subtrees which may contain one of the `k` nearest items are visited.
+
+    # Examples
+
+    ```
+    use bounding_box::{BoundingBox, RTree};
+
+    let tree = RTree::bulk_load((0..10).map(|i| [i as f64, 0.0]).map(BoundingBox::from).collect());
+
+    let nearest = tree.nearest_neighbors([3.4, 1.0], 3);
+    assert_eq!(nearest, vec![
+        &BoundingBox::from([3.0, 0.0]),
+        &BoundingBox::from([4.0, 0.0]),
+        &BoundingBox::from([2.0, 0.0]),
+    ]);
+    ```
+     */
+    pub fn nearest_neighbors<P: Into<[S; 2]>>(&self, point: P, k: usize) -> Vec<&T> {
+        let point: [S; 2] = point.into();
+        let mut out = Vec::with_capacity(k);
+        let root = match self.root.as_ref() {
+            Some(root) => root,
+            None => return out,
+        };
+        if k == 0 {
+            return out;
+        }
+
+        let mut heap = BinaryHeap::new();
+        heap.push(Candidate {
+            dist: root.bb.squared_distance_to_point(point),
+            kind: CandidateKind::Node(root),
+        });
+        while let Some(candidate) = heap.pop() {
+            match candidate.kind {
+                CandidateKind::Item(item) => {
+                    out.push(item);
+                    if out.len() == k {
+                        break;
+                    }
+                }
+                CandidateKind::Node(node) => match &node.children {
+                    Children::Leaf(entries) => {
+                        for entry in entries.iter() {
+                            heap.push(Candidate {
+                                dist: entry.bb.squared_distance_to_point(point),
+                                kind: CandidateKind::Item(&entry.item),
+                            });
+                        }
+                    }
+                    Children::Internal(nodes) => {
+                        for node in nodes.iter() {
+                            heap.push(Candidate {
+                                dist: node.bb.squared_distance_to_point(point),
+                                kind: CandidateKind::Node(node),
+                            });
+                        }
+                    }
+                },
+            }
+        }
+        return out;
+    }
+}
+
+impl<T: ToBoundingBox<S>, S: Scalar> Default for RTree<T, S> {
+    fn default() -> Self {
+        return RTree::new();
+    }
+}
+
+/// Element of the priority queue used by the k-nearest-neighbour search.
+struct Candidate<'a, T, S> {
+    dist: S,
+    kind: CandidateKind<'a, T, S>,
+}
+
+enum CandidateKind<'a, T, S> {
+    Node(&'a Node<T, S>),
+    Item(&'a T),
+}
+
+impl<T, S: Scalar> PartialEq for Candidate<'_, T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl<T, S: Scalar> Eq for Candidate<'_, T, S> {}
+
+impl<T, S: Scalar> PartialOrd for Candidate<'_, T, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl<T, S: Scalar> Ord for Candidate<'_, T, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, since BinaryHeap is a max-heap. For equal distances, items
+        // are preferred over nodes.
+        return other
+            .dist
+            .partial_cmp(&self.dist)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| {
+                let is_item = |c: &Self| matches!(c.kind, CandidateKind::Item(_));
+                is_item(self).cmp(&is_item(other))
+            });
+    }
+}
+
+fn union_all<S: Scalar>(mut boxes: impl Iterator<Item = BoundingBox<S>>) -> BoundingBox<S> {
+    let first = boxes.next().expect("at least one bounding box");
+    return boxes.fold(first, |acc, bb| acc.union(&bb));
+}
+
+fn area<S: Scalar>(bb: &BoundingBox<S>) -> S {
+    return bb.width() * bb.height();
+}
+
+fn cmp_scalar<S: Scalar>(a: S, b: S) -> Ordering {
+    return a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+}
+
+/// Returns the index of the node which needs the least area enlargement to
+/// cover `bb`. Ties are resolved by choosing the node with the smallest area.
+fn choose_subtree<T, S: Scalar>(nodes: &[Node<T, S>], bb: &BoundingBox<S>) -> usize {
+    let mut best = 0;
+    let mut best_enlargement = None;
+    let mut best_area = S::ZERO;
+    for (idx, node) in nodes.iter().enumerate() {
+        let node_area = area(&node.bb);
+        let enlargement = area(&node.bb.union(bb)) - node_area;
+        let is_better = match best_enlargement {
+            None => true,
+            Some(best_enl) => {
+                enlargement < best_enl || (enlargement == best_enl && node_area < best_area)
+            }
+        };
+        if is_better {
+            best = idx;
+            best_enlargement = Some(enlargement);
+            best_area = node_area;
+        }
+    }
+    return best;
+}
+
+/// Splits an overflowing node using Guttman's quadratic split algorithm.
+fn quadratic_split<X, S: Scalar>(
+    mut items: Vec<X>,
+    bb_of: impl Fn(&X) -> BoundingBox<S>,
+) -> (Vec<X>, Vec<X>) {
+    // Pick the two seeds which would waste the most area if put together
+    let mut seeds = (0, 1);
+    let mut max_waste = None;
+    for i in 0..items.len() {
+        for j in (i + 1)..items.len() {
+            let (bb_i, bb_j) = (bb_of(&items[i]), bb_of(&items[j]));
+            let waste = area(&bb_i.union(&bb_j)) - area(&bb_i) - area(&bb_j);
+            if max_waste.is_none_or(|max| waste > max) {
+                max_waste = Some(waste);
+                seeds = (i, j);
+            }
+        }
+    }
+
+    // Remove the seed with the larger index first so the other one stays valid
+    let seed_b = items.swap_remove(seeds.1);
+    let seed_a = items.swap_remove(seeds.0);
+    let mut bb_a = bb_of(&seed_a);
+    let mut bb_b = bb_of(&seed_b);
+    let mut group_a = vec![seed_a];
+    let mut group_b = vec![seed_b];
+
+    while !items.is_empty() {
+        // If one group needs all remaining items to reach the minimum size,
+        // assign them all to it
+        if group_a.len() + items.len() == MIN_ENTRIES {
+            group_a.append(&mut items);
+            break;
+        }
+        if group_b.len() + items.len() == MIN_ENTRIES {
+            group_b.append(&mut items);
+            break;
+        }
+
+        // Pick the item with the strongest preference for one group
+        let mut next = 0;
+        let mut max_diff = None;
+        let mut prefers_a = true;
+        for (idx, item) in items.iter().enumerate() {
+            let bb = bb_of(item);
+            let d_a = area(&bb_a.union(&bb)) - area(&bb_a);
+            let d_b = area(&bb_b.union(&bb)) - area(&bb_b);
+            let diff = if d_a > d_b { d_a - d_b } else { d_b - d_a };
+            if max_diff.is_none_or(|max| diff > max) {
+                max_diff = Some(diff);
+                next = idx;
+                prefers_a = d_a < d_b
+                    || (d_a == d_b
+                        && (area(&bb_a) < area(&bb_b)
+                            || (area(&bb_a) == area(&bb_b) && group_a.len() <= group_b.len())));
+            }
+        }
+        let item = items.swap_remove(next);
+        if prefers_a {
+            bb_a = bb_a.union(&bb_of(&item));
+            group_a.push(item);
+        } else {
+            bb_b = bb_b.union(&bb_of(&item));
+            group_b.push(item);
+        }
+    }
+    return (group_a, group_b);
+}
+
+/// Sorts the items along the given axis by the center of their bounding box.
+fn sort_by_center<X, S: Scalar>(
+    items: &mut [X],
+    axis: usize,
+    bb_of: &impl Fn(&X) -> BoundingBox<S>,
+) {
+    items.sort_by(|a, b| cmp_scalar(bb_of(a).center()[axis], bb_of(b).center()[axis]));
+}
+
+/// Packs the items into groups of at most `MAX_ENTRIES` using the
+/// Sort-Tile-Recursive algorithm.
+fn str_pack<X, S: Scalar>(mut items: Vec<X>, bb_of: impl Fn(&X) -> BoundingBox<S>) -> Vec<Vec<X>> {
+    let num_groups = items.len().div_ceil(MAX_ENTRIES);
+    let num_slices = (num_groups as f64).sqrt().ceil() as usize;
+    let slice_len = num_slices * MAX_ENTRIES;
+
+    sort_by_center(&mut items, 0, &bb_of);
+    let mut groups = Vec::with_capacity(num_groups);
+    let mut items = items.into_iter().peekable();
+    while items.peek().is_some() {
+        let mut slice: Vec<X> = items.by_ref().take(slice_len).collect();
+        sort_by_center(&mut slice, 1, &bb_of);
+        let mut slice = slice.into_iter().peekable();
+        while slice.peek().is_some() {
+            groups.push(slice.by_ref().take(MAX_ENTRIES).collect());
+        }
+    }
+    return groups;
+}
+
+/// Builds a subtree of the given height using the Overlap Minimizing Top-down
+/// algorithm.
+fn omt_build<T, S: Scalar>(mut entries: Vec<Entry<T, S>>, height: usize) -> Node<T, S> {
+    if height == 1 {
+        return Node::leaf(entries);
+    }
+
+    // Number of items which fit into one subtree of the next lower level
+    let subtree_capacity = MAX_ENTRIES.pow((height - 1) as u32);
+    let num_subtrees = entries.len().div_ceil(subtree_capacity);
+    let num_slices = (num_subtrees as f64).sqrt().ceil() as usize;
+    let slice_len = entries.len().div_ceil(num_slices);
+
+    let bb_of = |e: &Entry<T, S>| e.bb;
+    sort_by_center(&mut entries, 0, &bb_of);
+    let mut nodes = Vec::with_capacity(num_subtrees);
+    let mut entries = entries.into_iter().peekable();
+    while entries.peek().is_some() {
+        let mut slice: Vec<_> = entries.by_ref().take(slice_len).collect();
+        sort_by_center(&mut slice, 1, &bb_of);
+        let group_len = slice.len().div_ceil(slice.len().div_ceil(subtree_capacity));
+        let mut slice = slice.into_iter().peekable();
+        while slice.peek().is_some() {
+            let group: Vec<_> = slice.by_ref().take(group_len).collect();
+            nodes.push(omt_build(group, height - 1));
+        }
+    }
+    return Node::internal(nodes);
+}
@@ -0,0 +1,226 @@
+/*!
+Defines the [`Scalar`] trait, which abstracts over the numeric type used for the
+extremas of a [`BoundingBox`](crate::BoundingBox).
+ */
+
+use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+
+/**
+A numeric type which can be used for the extremas of a
+[`BoundingBox`](crate::BoundingBox).
+
+This trait is implemented for the floating point types `f32` and `f64` as well
+as for the signed integer types `i8`, `i16`, `i32`, `i64`, `i128` and `isize`.
+Unsigned integers are not supported, since some operations (e.g.
+[`BoundingBox::scale`](crate::BoundingBox::scale) with a factor smaller than
+one) require negative intermediate values.
+
+User-defined numeric types (e.g. fixed-point numbers) can be used as well by
+implementing this trait for them:
+
+```
+use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+use bounding_box::{BoundingBox, Scalar};
+
+/// Fixed-point number with 8 fractional bits
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Fixed(i32);
+
+impl Add for Fixed {
+    type Output = Fixed;
+    fn add(self, rhs: Fixed) -> Fixed { Fixed(self.0 + rhs.0) }
+}
+impl Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, rhs: Fixed) -> Fixed { Fixed(self.0 - rhs.0) }
+}
+impl Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, rhs: Fixed) -> Fixed { Fixed((self.0 * rhs.0) >> 8) }
+}
+impl Div for Fixed {
+    type Output = Fixed;
+    fn div(self, rhs: Fixed) -> Fixed { Fixed((self.0 << 8) / rhs.0) }
+}
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, rhs: Fixed) { self.0 += rhs.0 }
+}
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, rhs: Fixed) { self.0 -= rhs.0 }
+}
+
+impl Scalar for Fixed {
+    const ZERO: Fixed = Fixed(0);
+    const ONE: Fixed = Fixed(1 << 8);
+
+    fn is_finite(self) -> bool { true }
+    fn to_f64(self) -> f64 { self.0 as f64 / 256.0 }
+    fn from_f64(val: f64) -> Fixed { Fixed((val * 256.0) as i32) }
+}
+
+let bb = BoundingBox::new(Fixed::from_f64(-1.0), Fixed::from_f64(1.0), Fixed::ZERO, Fixed::from_f64(3.0));
+assert_eq!(bb.width(), Fixed::from_f64(2.0));
+assert_eq!(bb.center(), [Fixed::ZERO, Fixed::from_f64(1.5)]);
+```
+ */
+pub trait Scalar:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    /**
+    The additive identity of the type.
+     */
+    const ZERO: Self;
+
+    /**
+    The multiplicative identity of the type.
+     */
+    const ONE: Self;
+
+    /**
+    Returns true if the value is neither infinite nor NaN. Types without a
+    representation for infinity (such as integers) always return `true`.
+     */
+    fn is_finite(self) -> bool;
+
+    /**
+    Returns true if the value is NaN ("not a number").
+
+    The default implementation checks whether the value is not comparable to
+    itself, which is the defining property of NaN.
+     */
+    fn is_nan(self) -> bool {
+        return self.partial_cmp(&self).is_none();
+    }
+
+    /**
+    Converts the value to a `f64`. This conversion may be lossy (e.g. for
+    `i64` values beyond 2^53).
+     */
+    fn to_f64(self) -> f64;
+
+    /**
+    Converts a `f64` to the type. This conversion may be lossy (e.g. rounding
+    towards zero for integers).
+     */
+    fn from_f64(val: f64) -> Self;
+
+    /**
+    Returns the square root of the value.
+
+    The default implementation computes the square root via `f64` using
+    [`Scalar::to_f64`] and [`Scalar::from_f64`], which means that the result is
+    rounded towards zero for integers.
+     */
+    fn sqrt(self) -> Self {
+        return Self::from_f64(self.to_f64().sqrt());
+    }
+
+    /**
+    Returns `self + rhs` or `None` if the addition overflows.
+
+    The default implementation never returns `None`, which is correct for
+    floating point types (where an overflow results in infinity). Types which
+    can overflow (such as integers) should override this method.
+     */
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        return Some(self + rhs);
+    }
+
+    /**
+    Returns `self - rhs` or `None` if the subtraction overflows.
+
+    Like [`Scalar::checked_add`], the default implementation never returns
+    `None`.
+     */
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        return Some(self - rhs);
+    }
+
+    /**
+    Returns the midpoint between `self` and `rhs`, where `self <= rhs`.
+
+    The default implementation computes `self + (rhs - self) / 2`, which does
+    not overflow as long as the distance between the values is representable.
+    The implementations for the primitive types use their `midpoint` methods,
+    which never overflow.
+     */
+    fn midpoint(self, rhs: Self) -> Self {
+        return self + (rhs - self) / (Self::ONE + Self::ONE);
+    }
+}
+
+macro_rules! impl_scalar_float {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+
+                fn is_finite(self) -> bool {
+                    return <$t>::is_finite(self);
+                }
+
+                fn to_f64(self) -> f64 {
+                    return self as f64;
+                }
+
+                fn from_f64(val: f64) -> Self {
+                    return val as $t;
+                }
+
+                fn sqrt(self) -> Self {
+                    return <$t>::sqrt(self);
+                }
+
+                fn midpoint(self, rhs: Self) -> Self {
+                    return <$t>::midpoint(self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_scalar_int {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn is_finite(self) -> bool {
+                    return true;
+                }
+
+                fn to_f64(self) -> f64 {
+                    return self as f64;
+                }
+
+                fn from_f64(val: f64) -> Self {
+                    return val as $t;
+                }
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    return <$t>::checked_add(self, rhs);
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    return <$t>::checked_sub(self, rhs);
+                }
+
+                fn midpoint(self, rhs: Self) -> Self {
+                    return <$t>::midpoint(self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_float!(f32, f64);
+impl_scalar_int!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,159 @@
+/*!
+Sort-and-sweep (also known as sweep-and-prune) algorithms for finding all pairs
+of colliding bounding boxes.
+ */
+
+use std::cmp::Ordering;
+
+use crate::{BoundingBox, IntersectionTest, Scalar, ToBoundingBox};
+
+/// Returns true if `active` can no longer collide with any box starting at
+/// `xmin` or later.
+fn is_expired<S: Scalar>(active: &BoundingBox<S>, xmin: S, test: IntersectionTest) -> bool {
+    match test {
+        IntersectionTest::Intersects => return active.xmax() < xmin,
+        IntersectionTest::Overlaps => return active.xmax() <= xmin,
+    }
+}
+
+/// Returns the indices of `boxes` sorted by ascending `xmin`.
+fn sorted_by_xmin<S: Scalar>(boxes: &[BoundingBox<S>]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..boxes.len()).collect();
+    order.sort_by(|a, b| {
+        boxes[*a]
+            .xmin()
+            .partial_cmp(&boxes[*b].xmin())
+            .unwrap_or(Ordering::Equal)
+    });
+    return order;
+}
+
+/**
+Finds all pairs of colliding bounding boxes within `items` using the
+sort-and-sweep algorithm.
+
+The items are sorted by the minimum x-value of their bounding boxes and then
+swept from left to right while maintaining a list of "active" boxes whose
+x-interval overlaps the sweep position. Only active boxes need to be tested
+against each other, which reduces the number of tests from quadratic to roughly
+linear for typical inputs.
+
+Whether two boxes are colliding is decided by `test`: Either the closed
+[`BoundingBox::intersects`] (touching boxes collide) or the open
+[`BoundingBox::overlaps`] (touching boxes don't collide).
+
+The returned pairs `(i, j)` are indices into `items` with `i < j`. Each pair is
+reported exactly once, but the pairs are not sorted.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, IntersectionTest, sweep_and_prune};
+
+let boxes = [
+    BoundingBox::new(0.0, 2.0, 0.0, 1.0),
+    BoundingBox::new(5.0, 6.0, 0.0, 1.0),
+    BoundingBox::new(1.0, 3.0, 0.5, 2.0),
+    BoundingBox::new(3.0, 4.0, 0.0, 1.0), // Touches box 2
+];
+
+let mut pairs = sweep_and_prune(&boxes, IntersectionTest::Intersects);
+pairs.sort();
+assert_eq!(pairs, vec![(0, 2), (2, 3)]);
+
+let pairs = sweep_and_prune(&boxes, IntersectionTest::Overlaps);
+assert_eq!(pairs, vec![(0, 2)]);
+```
+ */
+pub fn sweep_and_prune<E: ToBoundingBox<S>, S: Scalar>(
+    items: &[E],
+    test: IntersectionTest,
+) -> Vec<(usize, usize)> {
+    let boxes: Vec<BoundingBox<S>> = items.iter().map(|item| item.bounding_box()).collect();
+    let mut pairs = Vec::new();
+    let mut active: Vec<usize> = Vec::new();
+    for idx in sorted_by_xmin(&boxes) {
+        let bb = &boxes[idx];
+        active.retain(|other| !is_expired(&boxes[*other], bb.xmin(), test));
+        for other in active.iter() {
+            if test.evaluate(bb, &boxes[*other]) {
+                pairs.push((idx.min(*other), idx.max(*other)));
+            }
+        }
+        active.push(idx);
+    }
+    return pairs;
+}
+
+/**
+Finds all pairs of colliding bounding boxes between the two sets `red` and
+`blue` (bipartite or "red-blue" intersection) using the sort-and-sweep
+algorithm.
+
+This function works like [`sweep_and_prune`], except that pairs of boxes from
+the same set are never tested or reported. This is useful e.g. for finding all
+collisions between moving objects and static obstacles.
+
+The returned pairs `(i, j)` consist of an index `i` into `red` and an index
+`j` into `blue`. Each pair is reported exactly once, but the pairs are not
+sorted.
+
+# Examples
+
+```
+use bounding_box::{BoundingBox, IntersectionTest, sweep_and_prune_bipartite};
+
+let red = [
+    BoundingBox::new(0.0, 2.0, 0.0, 2.0),
+    BoundingBox::new(1.0, 3.0, 1.0, 3.0), // Overlaps red box 0, which is ignored
+];
+let blue = [
+    BoundingBox::new(2.5, 4.0, 0.0, 1.5),
+    BoundingBox::new(-1.0, 0.0, 0.0, 1.0), // Touches red box 0
+];
+
+let mut pairs = sweep_and_prune_bipartite(&red, &blue, IntersectionTest::Intersects);
+pairs.sort();
+assert_eq!(pairs, vec![(0, 1), (1, 0)]);
+
+let pairs = sweep_and_prune_bipartite(&red, &blue, IntersectionTest::Overlaps);
+assert_eq!(pairs, vec![(1, 0)]);
+```
+ */
+pub fn sweep_and_prune_bipartite<R: ToBoundingBox<S>, B: ToBoundingBox<S>, S: Scalar>(
+    red: &[R],
+    blue: &[B],
+    test: IntersectionTest,
+) -> Vec<(usize, usize)> {
+    // Both sets are swept together; indices >= red.len() belong to blue
+    let boxes: Vec<BoundingBox<S>> = red
+        .iter()
+        .map(|item| item.bounding_box())
+        .chain(blue.iter().map(|item| item.bounding_box()))
+        .collect();
+    let num_red = red.len();
+    let mut pairs = Vec::new();
+    let mut active_red: Vec<usize> = Vec::new();
+    let mut active_blue: Vec<usize> = Vec::new();
+    for idx in sorted_by_xmin(&boxes) {
+        let bb = &boxes[idx];
+        active_red.retain(|other| !is_expired(&boxes[*other], bb.xmin(), test));
+        active_blue.retain(|other| !is_expired(&boxes[*other], bb.xmin(), test));
+        if idx < num_red {
+            for other in active_blue.iter() {
+                if test.evaluate(bb, &boxes[*other]) {
+                    pairs.push((idx, *other - num_red));
+                }
+            }
+            active_red.push(idx);
+        } else {
+            for other in active_red.iter() {
+                if test.evaluate(bb, &boxes[*other]) {
+                    pairs.push((*other, idx - num_red));
+                }
+            }
+            active_blue.push(idx);
+        }
+    }
+    return pairs;
+}
@@ -0,0 +1,93 @@
+/*!
+Affine transformations of [`BoundingBox`] instances.
+ */
+
+use crate::BoundingBox;
+
+impl BoundingBox<f64> {
+    /**
+    Applies the affine transformation `matrix` to the box and returns the
+    tightest bounding box of the transformed rectangle.
+
+    The matrix `[[a, b, tx], [c, d, ty]]` maps a point `[x, y]` to
+    `[a * x + b * y + tx, c * x + d * y + ty]`. This covers translations,
+    rotations, scalings, mirrorings and shears as well as any combination of
+    them. The result is identical to transforming the four corners and calling
+    [`BoundingBox::from_points`], but it is computed without constructing the
+    corners. Infinite boxes are supported: products of a zero matrix
+    coefficient and an infinite extremum are treated as zero.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+
+    let bb = BoundingBox::new(0.0, 2.0, 0.0, 1.0);
+
+    // Shear in x-direction
+    let sheared = bb.transform([[1.0, 1.0, 0.0], [0.0, 1.0, 0.0]]);
+    assert_eq!(sheared, BoundingBox::new(0.0, 3.0, 0.0, 1.0));
+
+    // Swap of x and y plus translation
+    let swapped = bb.transform([[0.0, 1.0, 5.0], [1.0, 0.0, 0.0]]);
+    assert_eq!(swapped, BoundingBox::new(5.0, 6.0, 0.0, 2.0));
+    ```
+     */
+    pub fn transform(&self, matrix: [[f64; 3]; 2]) -> BoundingBox<f64> {
+        let mins = [self.xmin(), self.ymin()];
+        let maxs = [self.xmax(), self.ymax()];
+        let mut lower = [matrix[0][2], matrix[1][2]];
+        let mut upper = lower;
+        for row in 0..2 {
+            for col in 0..2 {
+                let coeff = matrix[row][col];
+                if coeff == 0.0 {
+                    continue;
+                }
+                let a = coeff * mins[col];
+                let b = coeff * maxs[col];
+                lower[row] += a.min(b);
+                upper[row] += a.max(b);
+            }
+        }
+        return BoundingBox::new(lower[0], upper[0], lower[1], upper[1]);
+    }
+
+    /**
+    Rotates the box counterclockwise by `angle` (in radians) around `pivot`
+    and returns the tightest bounding box of the rotated rectangle.
+
+    See [`BoundingBox::transform`] for details.
+
+    # Examples
+
+    ```
+    use bounding_box::BoundingBox;
+    use std::f64::consts::FRAC_PI_2;
+
+    let bb = BoundingBox::new(0.0, 2.0, 0.0, 1.0);
+
+    // Rotation by 90° around the origin
+    let rotated = bb.rotated(FRAC_PI_2, [0.0, 0.0]);
+    assert!((rotated.xmin() + 1.0).abs() < 1e-12);
+    assert!(rotated.xmax().abs() < 1e-12);
+    assert!(rotated.ymin().abs() < 1e-12);
+    assert!((rotated.ymax() - 2.0).abs() < 1e-12);
+
+    // Rotation by 45° around the center
+    let rotated = bb.rotated(FRAC_PI_2 / 2.0, bb.center());
+    let expected = 1.5 * 2.0f64.sqrt();
+    assert!((rotated.width() - expected).abs() < 1e-12);
+    assert!((rotated.height() - expected).abs() < 1e-12);
+    assert!((rotated.center()[1] - 0.5).abs() < 1e-12);
+    ```
+     */
+    pub fn rotated<P: Into<[f64; 2]>>(&self, angle: f64, pivot: P) -> BoundingBox<f64> {
+        let [px, py]: [f64; 2] = pivot.into();
+        let (sin, cos) = angle.sin_cos();
+        return self.transform([
+            [cos, -sin, px - cos * px + sin * py],
+            [sin, cos, py - sin * px - cos * py],
+        ]);
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::needless_return, clippy::legacy_numeric_constants)]
+
 use bounding_box::*;
 
 #[test]
@@ -183,3 +185,1815 @@ fn test_to_bounding_box() {
     assert_eq!(bb.xmax(), 4.0);
     assert_eq!(bb.ymax(), 4.0);
 }
+
+#[test]
+fn test_generic_scalar() {
+    let bb = BoundingBox::new(0.0f32, 2.0, -1.0, 1.0);
+    assert_eq!(bb.width(), 2.0f32);
+    assert_eq!(bb.center(), [1.0f32, 0.0]);
+    assert!(bb.covers_point([0.5f32, 0.5]));
+
+    let mut bb = BoundingBox::new(0i32, 4, 0, 2);
+    assert_eq!(bb.center(), [2, 1]);
+    assert!(bb.intersects(&BoundingBox::new(4, 5, 2, 3)));
+    assert!(!bb.overlaps(&BoundingBox::new(4, 5, 2, 3)));
+    bb.scale(2);
+    assert_eq!(bb, BoundingBox::new(-2, 6, -1, 3));
+    bb.translate([2, 1]);
+    assert_eq!(bb, BoundingBox::new(0, 8, 0, 4));
+    assert!(BoundingBox::try_new(1i64, 0, 0, 0).is_none());
+
+    let bb = BoundingBox::from_points([[1i64, 2], [-3, 5], [0, -1]].into_iter()).unwrap();
+    assert_eq!(bb, BoundingBox::new(-3, 1, -1, 5));
+
+    // The center is computed without overflow and rounded towards zero
+    let bb = BoundingBox::new(i32::MAX - 1, i32::MAX, i32::MIN, i32::MIN + 1);
+    assert_eq!(bb.center(), [i32::MAX - 1, i32::MIN + 1]);
+    assert_eq!(BoundingBox::new(-3, 0, 0, 3).center(), [-1, 1]);
+    let bb = BoundingBox::new(-f64::MAX, f64::MAX, f64::MAX, f64::MAX);
+    assert_eq!(bb.center(), [0.0, f64::MAX]);
+}
+
+#[test]
+fn test_generic_to_bounding_box() {
+    struct Pixel {
+        x: i32,
+        y: i32,
+    }
+
+    impl ToBoundingBox<i32> for Pixel {
+        fn bounding_box(&self) -> BoundingBox<i32> {
+            return BoundingBox::new(self.x, self.x + 1, self.y, self.y + 1);
+        }
+    }
+
+    let pixels = [Pixel { x: 0, y: 0 }, Pixel { x: 3, y: -2 }];
+    let bb = BoundingBox::from_bounded_entities(pixels.iter()).unwrap();
+    assert_eq!(bb, BoundingBox::new(0, 4, -2, 1));
+}
+
+#[test]
+fn test_convert_and_cast() {
+    let bb = BoundingBox::new(0.5f32, 1.5, -2.0, 2.0);
+    let bb_f64: BoundingBox = bb.convert();
+    assert_eq!(bb_f64, BoundingBox::new(0.5, 1.5, -2.0, 2.0));
+
+    let bb_i32: BoundingBox<i32> = bb_f64.cast();
+    assert_eq!(bb_i32, BoundingBox::new(0, 1, -2, 2));
+
+    let bb_i64: BoundingBox<i64> = bb_i32.convert();
+    assert_eq!(bb_i64, BoundingBox::new(0, 1, -2, 2));
+}
+
+#[test]
+fn test_bounding_box3() {
+    let bb1 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox3::new(1.0, 2.0, 0.5, 1.5, 0.0, 1.0);
+    assert!(bb1.intersects(&bb2));
+    assert!(!bb1.overlaps(&bb2));
+    assert!(bb1.touches(&bb2));
+    assert_eq!(
+        bb1.union(&bb2),
+        BoundingBox3::new(0.0, 2.0, 0.0, 1.5, 0.0, 1.0)
+    );
+    assert_eq!(bb1.union(&bb2).volume(), 3.0);
+
+    let bb3 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 1.5, 2.0);
+    assert!(!bb1.intersects(&bb3));
+    assert!(!bb1.touches(&bb3));
+
+    let bb = BoundingBox3::from_points([[0i32, 1, 2], [-1, 4, 0]].into_iter()).unwrap();
+    assert_eq!(bb, BoundingBox3::new(-1, 0, 1, 4, 0, 2));
+    assert_eq!(
+        BoundingBox3::new(i32::MAX - 1, i32::MAX, 0, 1, -3, 0).center(),
+        [i32::MAX - 1, 0, -1]
+    );
+
+    // A collection of boxes can be merged like in 2D
+    let boxes: Vec<BoundingBox3> = [bb1, bb2, bb3].to_vec();
+    assert_eq!(
+        BoundingBox3::from_bounded_entities(boxes.iter()),
+        Some(BoundingBox3::new(0.0, 2.0, 0.0, 1.5, 0.0, 2.0))
+    );
+
+    // Setters
+    let mut bb = bb1;
+    assert!(bb.try_set_xmin(-1.0));
+    assert!(bb.try_set_ymax(3.0));
+    assert!(bb.try_set_zmax(0.5));
+    assert!(!bb.try_set_zmin(0.75));
+    assert!(!bb.try_set_xmax(-2.0));
+    assert_eq!(bb, BoundingBox3::new(-1.0, 1.0, 0.0, 3.0, 0.0, 0.5));
+}
+
+#[test]
+fn test_to_bounding_box3() {
+    struct Cube {
+        origin: [f64; 3],
+        edge: f64,
+    }
+
+    impl ToBoundingBox3 for Cube {
+        fn bounding_box3(&self) -> BoundingBox3 {
+            return BoundingBox3::new(
+                self.origin[0],
+                self.origin[0] + self.edge,
+                self.origin[1],
+                self.origin[1] + self.edge,
+                self.origin[2],
+                self.origin[2] + self.edge,
+            );
+        }
+    }
+
+    let c1 = Cube {
+        origin: [0.0, 0.0, 0.0],
+        edge: 1.0,
+    };
+    let c2 = Cube {
+        origin: [2.0, -1.0, 0.5],
+        edge: 2.0,
+    };
+    assert_eq!(BoundingBox3::from(&c1), c1.bounding_box3());
+    let bb = BoundingBox3::from_bounded_entities([&c1, &c2].into_iter()).unwrap();
+    assert_eq!(bb, BoundingBox3::new(0.0, 4.0, -1.0, 1.0, 0.0, 2.5));
+}
+
+#[test]
+fn test_intersection() {
+    let bb1 = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    let bb2 = BoundingBox::new(0.5, 2.0, -1.0, 0.5);
+    assert_eq!(
+        bb1.intersection(&bb2),
+        Some(BoundingBox::new(0.5, 1.0, 0.0, 0.5))
+    );
+    assert_eq!(bb1.intersection(&bb2), bb2.intersection(&bb1));
+
+    // Touching at a corner
+    let bb2 = BoundingBox::new(1.0, 2.0, 1.0, 2.0);
+    assert_eq!(
+        bb1.intersection(&bb2),
+        Some(BoundingBox::new(1.0, 1.0, 1.0, 1.0))
+    );
+
+    // Infinite boxes
+    let bb2 = BoundingBox::new(0.2, 0.8, f64::NEG_INFINITY, f64::INFINITY);
+    assert_eq!(
+        bb1.intersection(&bb2),
+        Some(BoundingBox::new(0.2, 0.8, 0.0, 1.0))
+    );
+
+    let bb2 = BoundingBox::new(1.5, 2.0, 0.0, 1.0);
+    assert!(bb1.intersection(&bb2).is_none());
+
+    let mut bb = bb1;
+    assert!(!bb.clip_to(&bb2));
+    assert_eq!(bb, bb1);
+
+    let bb = BoundingBox::from_intersecting_entities(std::iter::empty::<BoundingBox>());
+    assert!(bb.is_none());
+}
+
+#[test]
+fn test_difference() {
+    let bb = BoundingBox::new(0.0, 4.0, 0.0, 2.0);
+
+    // Subtract a corner
+    let corner = BoundingBox::new(3.0, 5.0, 1.0, 3.0);
+    let pieces: Vec<_> = bb.difference(&corner).collect();
+    assert_eq!(
+        pieces,
+        vec![
+            BoundingBox::new(0.0, 3.0, 0.0, 2.0),
+            BoundingBox::new(3.0, 4.0, 0.0, 1.0)
+        ]
+    );
+    for piece in pieces.iter() {
+        assert!(bb.covers(piece));
+        assert!(!piece.overlaps(&corner));
+    }
+
+    // Subtract a vertical band crossing the box
+    let band = BoundingBox::new(1.0, 2.0, f64::NEG_INFINITY, f64::INFINITY);
+    let pieces: Vec<_> = bb.difference(&band).collect();
+    assert_eq!(
+        pieces,
+        vec![
+            BoundingBox::new(0.0, 1.0, 0.0, 2.0),
+            BoundingBox::new(2.0, 4.0, 0.0, 2.0)
+        ]
+    );
+
+    // Integer boxes
+    let pieces: Vec<_> = BoundingBox::new(0, 10, 0, 10)
+        .difference(&BoundingBox::new(0, 5, 0, 10))
+        .collect();
+    assert_eq!(pieces, vec![BoundingBox::new(5, 10, 0, 10)]);
+}
+
+#[test]
+fn test_disjoint_cover() {
+    let boxes = [
+        BoundingBox::new(0, 4, 0, 4),
+        BoundingBox::new(2, 6, 2, 6),
+        BoundingBox::new(-2, 8, 3, 5),
+        BoundingBox::new(10, 12, 10, 12),
+    ];
+    let cover: Vec<_> = BoundingBox::disjoint_cover(boxes.iter().copied()).collect();
+
+    for (i, a) in cover.iter().enumerate() {
+        for b in cover[(i + 1)..].iter() {
+            assert!(!a.overlaps(b));
+        }
+    }
+
+    // Every cell of the integer grid is covered by the input iff it is covered
+    // by the output
+    for x in -3..13 {
+        for y in -1..13 {
+            let cell = BoundingBox::new(x, x + 1, y, y + 1);
+            let in_input = boxes.iter().any(|bb| bb.covers(&cell));
+            let count = cover.iter().filter(|bb| bb.covers(&cell)).count();
+            assert_eq!(in_input, count == 1);
+            assert!(count <= 1);
+        }
+    }
+}
+
+#[test]
+fn test_distances() {
+    let bb = BoundingBox::new(-1.0, 1.0, 0.0, 2.0);
+
+    // Inside and on the boundary
+    assert_eq!(bb.distance_to_point([0.0, 1.0]), 0.0);
+    assert_eq!(bb.distance_to_point([1.0, 2.0]), 0.0);
+
+    // Nalgebra types are accepted as well
+    let pt = nalgebra::Point2::new(4.0, 6.0);
+    assert_eq!(bb.closest_point(pt), [1.0, 2.0]);
+    assert_eq!(bb.squared_distance_to_point(pt), 25.0);
+    assert_eq!(bb.distance_to_point(pt), 5.0);
+    assert_eq!(bb.max_distance_to_point([0.0, 1.0]), 2.0f64.sqrt());
+
+    // Box distance is symmetric and zero for intersecting boxes
+    let other = BoundingBox::new(-5.0, -4.0, -5.0, -4.0);
+    assert_eq!(bb.distance_to_box(&other), 5.0);
+    assert_eq!(other.distance_to_box(&bb), 5.0);
+    assert_eq!(
+        bb.distance_to_box(&BoundingBox::new(0.0, 3.0, 1.0, 3.0)),
+        0.0
+    );
+
+    // Integer boxes
+    let bb = BoundingBox::new(0i32, 2, 0, 2);
+    assert_eq!(bb.squared_distance_to_point([5, 6]), 25);
+    assert_eq!(bb.distance_to_point([5, 6]), 5);
+}
+
+/// Returns the next number in `[0, 1)` of a simple linear congruential generator
+fn next_pseudo_random(state: &mut u64) -> f64 {
+    *state = state
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    return (*state >> 33) as f64 / (1u64 << 31) as f64;
+}
+
+/// Simple deterministic pseudo-random boxes of up to 5 x 5 within
+/// `[0, 105] x [0, 105]`, used as box fixtures (e.g. for the spatial indices)
+fn pseudo_random_boxes(n: usize) -> Vec<BoundingBox> {
+    let mut state: u64 = 12345;
+    let mut next = || next_pseudo_random(&mut state);
+    return (0..n)
+        .map(|_| {
+            let x = 100.0 * next();
+            let y = 100.0 * next();
+            BoundingBox::new(x, x + 5.0 * next(), y, y + 5.0 * next())
+        })
+        .collect();
+}
+
+/// Simple deterministic pseudo-random points within `[0, 100] x [0, 100]`,
+/// used as point clouds and curve control points
+fn pseudo_random_points(n: usize) -> Vec<[f64; 2]> {
+    let mut state: u64 = 67890;
+    let mut next = || next_pseudo_random(&mut state);
+    return (0..n).map(|_| [100.0 * next(), 100.0 * next()]).collect();
+}
+
+#[test]
+fn test_rtree_queries() {
+    let boxes = pseudo_random_boxes(1000);
+    let queries = [
+        BoundingBox::new(10.0, 30.0, 40.0, 45.0),
+        BoundingBox::new(50.0, 51.0, 50.0, 51.0),
+        BoundingBox::new(-10.0, 200.0, -10.0, 200.0),
+    ];
+
+    let mut incremental = RTree::new();
+    for bb in boxes.iter() {
+        incremental.insert(*bb);
+    }
+    let trees = [
+        RTree::bulk_load(boxes.clone()),
+        RTree::bulk_load_omt(boxes.clone()),
+        incremental,
+    ];
+
+    for tree in trees.iter() {
+        assert_eq!(tree.len(), boxes.len());
+        assert_eq!(tree.iter().count(), boxes.len());
+        for query in queries.iter() {
+            let expected = boxes.iter().filter(|bb| bb.intersects(query)).count();
+            assert_eq!(tree.query_intersecting(query).len(), expected);
+
+            let expected = boxes.iter().filter(|bb| query.covers(bb)).count();
+            assert_eq!(tree.query_covered_by(query).len(), expected);
+
+            let expected = boxes.iter().filter(|bb| bb.covers(query)).count();
+            assert_eq!(tree.query_covering(query).len(), expected);
+
+            let expected = boxes
+                .iter()
+                .filter(|bb| bb.covers_point(query.center()))
+                .count();
+            assert_eq!(tree.query_point(query.center()).len(), expected);
+        }
+
+        let point = [42.0, 17.0];
+        let mut dists: Vec<f64> = boxes
+            .iter()
+            .map(|bb| bb.squared_distance_to_point(point))
+            .collect();
+        dists.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let nearest = tree.nearest_neighbors(point, 10);
+        assert_eq!(nearest.len(), 10);
+        for (bb, dist) in nearest.iter().zip(dists.iter()) {
+            assert_eq!(bb.squared_distance_to_point(point), *dist);
+        }
+    }
+}
+
+#[test]
+fn test_rtree_remove() {
+    let boxes = pseudo_random_boxes(500);
+    let mut tree = RTree::bulk_load(boxes.clone());
+
+    for (idx, bb) in boxes.iter().enumerate() {
+        if idx % 3 != 0 {
+            assert_eq!(tree.remove(bb), Some(*bb));
+        }
+    }
+    assert!(
+        tree.remove(&BoundingBox::new(-5.0, -4.0, 0.0, 1.0))
+            .is_none()
+    );
+
+    let remaining: Vec<_> = boxes.iter().step_by(3).copied().collect();
+    assert_eq!(tree.len(), remaining.len());
+    let query = BoundingBox::new(20.0, 60.0, 20.0, 60.0);
+    let expected = remaining.iter().filter(|bb| bb.intersects(&query)).count();
+    assert_eq!(tree.query_intersecting(&query).len(), expected);
+    assert_eq!(
+        tree.bounding_box(),
+        BoundingBox::from_bounded_entities(remaining.iter())
+    );
+
+    for bb in remaining.iter() {
+        assert!(tree.remove(bb).is_some());
+    }
+    assert!(tree.is_empty());
+    assert!(tree.bounding_box().is_none());
+}
+
+#[test]
+fn test_quadtree() {
+    let boxes = pseudo_random_boxes(1000);
+    let mut tree = QuadTree::new(BoundingBox::new(0.0, 100.0, 0.0, 100.0));
+    let keys: Vec<_> = boxes.iter().map(|bb| tree.insert(*bb)).collect();
+    assert_eq!(tree.len(), boxes.len());
+
+    // Item outside of the root region
+    let outside = tree.insert(BoundingBox::new(150.0, 160.0, -20.0, -10.0));
+    assert_eq!(tree.query_point([155.0, -15.0]).len(), 1);
+    assert_eq!(
+        tree.remove(outside),
+        Some(BoundingBox::new(150.0, 160.0, -20.0, -10.0))
+    );
+
+    let check = |tree: &QuadTree<BoundingBox>| {
+        let items: Vec<BoundingBox> = tree.iter().map(|(_, bb)| *bb).collect();
+        let query = BoundingBox::new(30.0, 70.0, 45.0, 55.0);
+        let expected = items.iter().filter(|bb| bb.intersects(&query)).count();
+        assert_eq!(tree.query_intersecting(&query).len(), expected);
+
+        let expected = items
+            .iter()
+            .filter(|bb| bb.covers_point([50.0, 50.0]))
+            .count();
+        assert_eq!(tree.query_point([50.0, 50.0]).len(), expected);
+
+        let expected = items
+            .iter()
+            .filter(|bb| bb.distance_to_point([20.0, 80.0]) <= 7.5)
+            .count();
+        assert_eq!(tree.query_radius([20.0, 80.0], 7.5).len(), expected);
+        assert!(tree.query_radius([50.0, 50.0], -7.5).is_empty());
+    };
+    check(&tree);
+
+    // Move every second item
+    for key in keys.iter().step_by(2) {
+        tree.get_mut(*key).unwrap().translate([3.0, -7.0]);
+        assert!(tree.update(*key));
+    }
+    check(&tree);
+
+    // Remove most items, which merges nodes again
+    for key in keys.iter().skip(10) {
+        assert!(tree.remove(*key).is_some());
+    }
+    assert_eq!(tree.len(), 10);
+    check(&tree);
+    assert!(tree.remove(keys[500]).is_none());
+    assert!(!tree.update(keys[500]));
+}
+
+#[test]
+fn test_spatial_grid() {
+    let boxes = pseudo_random_boxes(1000);
+    let mut grid = SpatialGrid::new(BoundingBox::new(0.0, 100.0, 0.0, 100.0), 5.0);
+    for bb in boxes.iter() {
+        grid.insert(*bb);
+    }
+    // Item outside of the world box
+    grid.insert(BoundingBox::new(98.0, 120.0, -5.0, 3.0));
+    let mut all = boxes.clone();
+    all.push(BoundingBox::new(98.0, 120.0, -5.0, 3.0));
+
+    for test in [IntersectionTest::Intersects, IntersectionTest::Overlaps] {
+        let mut expected = Vec::new();
+        for i in 0..all.len() {
+            for j in (i + 1)..all.len() {
+                if test.evaluate(&all[i], &all[j]) {
+                    expected.push((i, j));
+                }
+            }
+        }
+        let mut pairs: Vec<_> = grid.pairs(test).collect();
+        pairs.sort();
+        assert_eq!(pairs, expected);
+    }
+
+    grid.clear();
+    assert!(grid.is_empty());
+    assert_eq!(grid.pairs(IntersectionTest::Intersects).count(), 0);
+
+    // Integer grid
+    let mut grid = SpatialGrid::new(BoundingBox::new(0, 64, 0, 64), 8);
+    grid.insert(BoundingBox::new(0, 8, 0, 8));
+    grid.insert(BoundingBox::new(8, 16, 0, 8));
+    assert_eq!(
+        grid.pairs(IntersectionTest::Intersects).collect::<Vec<_>>(),
+        vec![(0, 1)]
+    );
+    assert_eq!(grid.pairs(IntersectionTest::Overlaps).count(), 0);
+}
+
+#[test]
+fn test_sweep_and_prune() {
+    let boxes = pseudo_random_boxes(800);
+    let (red, blue) = boxes.split_at(300);
+
+    for test in [IntersectionTest::Intersects, IntersectionTest::Overlaps] {
+        let mut expected = Vec::new();
+        for i in 0..boxes.len() {
+            for j in (i + 1)..boxes.len() {
+                if test.evaluate(&boxes[i], &boxes[j]) {
+                    expected.push((i, j));
+                }
+            }
+        }
+        let mut pairs = sweep_and_prune(&boxes, test);
+        pairs.sort();
+        assert_eq!(pairs, expected);
+
+        let mut expected = Vec::new();
+        for (i, bb_red) in red.iter().enumerate() {
+            for (j, bb_blue) in blue.iter().enumerate() {
+                if test.evaluate(bb_red, bb_blue) {
+                    expected.push((i, j));
+                }
+            }
+        }
+        let mut pairs = sweep_and_prune_bipartite(red, blue, test);
+        pairs.sort();
+        assert_eq!(pairs, expected);
+    }
+
+    // Boxes sharing only an edge at the sweep position
+    let boxes = [
+        BoundingBox::new(0, 1, 0, 1),
+        BoundingBox::new(1, 2, 0, 1),
+        BoundingBox::new(1, 2, 1, 2),
+    ];
+    let mut pairs = sweep_and_prune(&boxes, IntersectionTest::Intersects);
+    pairs.sort();
+    assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 2)]);
+    assert!(sweep_and_prune(&boxes, IntersectionTest::Overlaps).is_empty());
+}
+
+#[test]
+fn test_bvh() {
+    let boxes = pseudo_random_boxes(500);
+    let query = BoundingBox::new(20.0, 45.0, 30.0, 60.0);
+
+    let check = |bvh: &Bvh<BoundingBox>| {
+        let mut expected: Vec<_> = bvh
+            .iter()
+            .filter(|(_, bb)| bb.intersects(&query))
+            .map(|(key, _)| key)
+            .collect();
+        let mut found: Vec<_> = bvh
+            .query_intersecting(&query)
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        expected.sort();
+        found.sort();
+        assert_eq!(found, expected);
+
+        let expected = bvh
+            .iter()
+            .filter(|(_, bb)| bb.covers_point([50.0, 50.0]))
+            .count();
+        assert_eq!(bvh.query_point([50.0, 50.0]).len(), expected);
+
+        let bb = BoundingBox::from_bounded_entities(bvh.iter().map(|(_, bb)| *bb));
+        assert_eq!(bvh.bounding_box(), bb);
+    };
+
+    for split in [BvhSplit::Median, BvhSplit::Sah] {
+        let mut bvh = Bvh::build(boxes.clone(), split);
+        assert_eq!(bvh.len(), 500);
+        check(&bvh);
+
+        // Move all items and refit
+        for (_, bb) in bvh.iter_mut() {
+            bb.translate([7.0, -3.0]);
+        }
+        bvh.refit();
+        check(&bvh);
+
+        // Incremental modifications
+        let keys: Vec<_> = bvh.iter().map(|(key, _)| key).collect();
+        for key in keys.iter().step_by(3) {
+            assert!(bvh.remove(*key).is_some());
+        }
+        for bb in boxes.iter().take(100) {
+            bvh.insert(*bb);
+        }
+        check(&bvh);
+        let key = bvh.iter().next().unwrap().0;
+        bvh.get_mut(key).unwrap().translate([-40.0, 0.0]);
+        assert!(bvh.update(key));
+        check(&bvh);
+    }
+
+    // Ray casts against boxes on a line
+    let mut bvh = Bvh::new();
+    for i in 0..10 {
+        let x = 10.0 * i as f64;
+        bvh.insert(BoundingBox::new(x, x + 1.0, 0.0, 1.0));
+    }
+    let hits = bvh.ray_cast([100.0, 0.5], [-1.0, 0.0]);
+    assert_eq!(hits.len(), 10);
+    assert_eq!(hits[0].2, 9.0);
+    assert_eq!(hits[9].2, 99.0);
+    assert!(bvh.ray_cast([0.0, 2.0], [1.0, 0.0]).is_empty());
+    assert_eq!(bvh.ray_cast([45.5, -1.0], [0.0, 1.0]).len(), 0);
+    assert_eq!(bvh.ray_cast([40.5, -1.0], [0.0, 1.0])[0].2, 1.0);
+}
+
+#[test]
+fn test_ray_intersection() {
+    let bb = BoundingBox::new(-1.0, 1.0, -1.0, 1.0);
+
+    // Rays from all four sides
+    for (origin, direction, side) in [
+        ([-3.0, 0.0], [1.0, 0.0], BoxSide::Left),
+        ([3.0, 0.0], [-1.0, 0.0], BoxSide::Right),
+        ([0.0, -3.0], [0.0, 1.0], BoxSide::Bottom),
+        ([0.0, 3.0], [0.0, -1.0], BoxSide::Top),
+    ] {
+        let hit = bb.ray_hit(origin, direction).unwrap();
+        assert_eq!((hit.t_enter, hit.t_exit), (2.0, 4.0));
+        assert_eq!(hit.entry_side, Some(side));
+        assert_eq!(
+            hit.entry,
+            [
+                origin[0] + 2.0 * direction[0],
+                origin[1] + 2.0 * direction[1]
+            ]
+        );
+    }
+
+    // Touching an edge and a corner
+    assert_eq!(
+        bb.ray_intersection([-3.0, 1.0], [1.0, 0.0]),
+        Some((2.0, 4.0))
+    );
+    assert_eq!(
+        bb.ray_intersection([-2.0, 0.0], [1.0, 1.0]),
+        Some((1.0, 1.0))
+    );
+    assert_eq!(bb.ray_intersection([-3.0, 1.1], [1.0, 0.0]), None);
+
+    // Degenerate direction
+    assert_eq!(
+        bb.ray_intersection([0.0, 0.0], [0.0, 0.0]),
+        Some((0.0, f64::INFINITY))
+    );
+    assert_eq!(bb.ray_intersection([2.0, 0.0], [0.0, 0.0]), None);
+    assert_eq!(
+        bb.segment_intersection([0.5, 0.5], [0.5, 0.5]),
+        Some((0.0, 1.0))
+    );
+
+    // Segments
+    assert_eq!(bb.segment_intersection([-3.0, 0.0], [-2.0, 0.0]), None);
+    assert_eq!(
+        bb.segment_intersection([-3.0, 0.0], [1.0, 0.0]),
+        Some((0.5, 1.0))
+    );
+    let hit = bb.segment_hit([-3.0, 0.0], [3.0, 0.0]).unwrap();
+    assert_eq!(hit.exit, Some([1.0, 0.0]));
+    assert_eq!(hit.exit_side, Some(BoxSide::Right));
+
+    // Infinite boxes
+    let bb = BoundingBox::new(f64::NEG_INFINITY, f64::INFINITY, 0.0, 1.0);
+    assert!(!bb.is_finite());
+    let hit = bb.ray_hit([5.0, -1.0], [1.0, 1.0]).unwrap();
+    assert_eq!((hit.t_enter, hit.t_exit), (1.0, 2.0));
+    let hit = bb.ray_hit([5.0, 0.5], [-1.0, 0.0]).unwrap();
+    assert_eq!((hit.t_enter, hit.t_exit), (0.0, f64::INFINITY));
+    assert_eq!(hit.exit, None);
+    assert_eq!(hit.exit_side, None);
+    assert_eq!(bb.ray_intersection([5.0, 2.0], [1.0, 0.0]), None);
+}
+
+#[test]
+fn test_clip_polyline() {
+    let bb = BoundingBox::new(0.0, 10.0, 0.0, 10.0);
+
+    // Zigzag line crossing the top side several times
+    let polyline: Vec<[f64; 2]> = (0..=10)
+        .map(|i| [i as f64, if i % 2 == 0 { 9.0 } else { 11.0 }])
+        .collect();
+    let pieces = bb.clip_polyline(polyline.iter().copied());
+    assert_eq!(pieces.len(), 6);
+    assert_eq!(pieces[0], vec![[0.0, 9.0], [0.5, 10.0]]);
+    assert_eq!(pieces[1], vec![[1.5, 10.0], [2.0, 9.0], [2.5, 10.0]]);
+    assert_eq!(pieces[5], vec![[9.5, 10.0], [10.0, 9.0]]);
+    for piece in pieces.iter() {
+        for point in piece.iter() {
+            assert!(bb.covers_point(*point));
+        }
+    }
+
+    // Polyline completely inside or outside
+    let inside = [[1.0, 1.0], [2.0, 3.0], [4.0, 2.0]];
+    assert_eq!(bb.clip_polyline(inside), vec![inside.to_vec()]);
+    assert!(
+        bb.clip_polyline([[-1.0, -1.0], [-1.0, 20.0], [20.0, 20.0]])
+            .is_empty()
+    );
+
+    // Vertices on the boundary are not duplicated as entry or exit points
+    let bb2 = BoundingBox::new(0.0, 2.0, 0.0, 2.0);
+    assert_eq!(
+        bb2.clip_polyline([[1.0, 1.0], [2.0, 1.0], [3.0, 1.0]]),
+        vec![vec![[1.0, 1.0], [2.0, 1.0]]]
+    );
+    assert_eq!(
+        bb2.clip_polyline([[-1.0, 1.0], [0.0, 1.0], [1.0, 1.0]]),
+        vec![vec![[0.0, 1.0], [1.0, 1.0]]]
+    );
+    assert_eq!(
+        bb2.clip_polyline([[-1.0, 1.0], [0.0, 1.0], [1.0, 1.0], [2.0, 1.0], [3.0, 1.0]]),
+        vec![vec![[0.0, 1.0], [1.0, 1.0], [2.0, 1.0]]]
+    );
+    assert_eq!(
+        bb2.clip_polyline([[-1.0, 1.0], [0.0, 1.0], [-1.0, 2.0]]),
+        vec![vec![[0.0, 1.0]]]
+    );
+    // ... while duplicate vertices of the input are kept
+    assert_eq!(
+        bb2.clip_polyline([[1.0, 1.0], [1.0, 1.0], [3.0, 1.0]]),
+        vec![vec![[1.0, 1.0], [1.0, 1.0], [2.0, 1.0]]]
+    );
+
+    // Degenerate polylines
+    assert!(bb.clip_polyline(Vec::<[f64; 2]>::new()).is_empty());
+    assert_eq!(bb.clip_polyline([[5.0, 5.0]]), vec![vec![[5.0, 5.0]]]);
+    assert!(bb.clip_polyline([[15.0, 5.0]]).is_empty());
+
+    // Segment crossing the whole box
+    assert_eq!(
+        bb.clip_segment([-5.0, 5.0], [15.0, 5.0]),
+        Some([[0.0, 5.0], [10.0, 5.0]])
+    );
+
+    // Approximate handling of vertices on the boundary
+    let polyline = [[2.0, 5.0], [10.0 + 1e-12, 5.0], [2.0, 6.0]];
+    assert_eq!(bb.clip_polyline(polyline).len(), 2);
+    let pieces = bb.approx_clip_polyline(polyline, 1e-9, 0);
+    assert_eq!(pieces, vec![vec![[2.0, 5.0], [10.0, 5.0], [2.0, 6.0]]]);
+    assert_eq!(
+        bb.approx_clip_segment([10.0 + 1e-12, 2.0], [10.0 + 1e-12, 8.0], 1e-9, 0),
+        Some([[10.0, 2.0], [10.0, 8.0]])
+    );
+}
+
+#[test]
+fn test_clip_polygon() {
+    let bb = BoundingBox::new(0.0, 10.0, 0.0, 10.0);
+    let area = |polygon: &[[f64; 2]]| {
+        let mut area = 0.0;
+        for (idx, current) in polygon.iter().enumerate() {
+            let next = &polygon[(idx + 1) % polygon.len()];
+            area += current[0] * next[1] - next[0] * current[1];
+        }
+        return (0.5 * area).abs();
+    };
+
+    // Polygon inside the box is unchanged
+    let inside = [[1.0, 1.0], [5.0, 1.0], [3.0, 4.0]];
+    assert_eq!(bb.clip_polygon(inside), inside.to_vec());
+
+    // Box inside the polygon results in the box
+    let clipped = bb.clip_polygon([[-5.0, -5.0], [15.0, -5.0], [15.0, 15.0], [-5.0, 15.0]]);
+    assert_eq!(BoundingBox::from_points(clipped.iter().copied()), Some(bb));
+    assert_eq!(area(&clipped), 100.0);
+
+    // Diamond clipped to the box
+    let diamond = [[5.0, -2.0], [12.0, 5.0], [5.0, 12.0], [-2.0, 5.0]];
+    let clipped = bb.clip_polygon(diamond);
+    assert_eq!(clipped.len(), 8);
+    assert_eq!(area(&clipped), 98.0 - 4.0 * 4.0);
+    for vertex in clipped.iter() {
+        assert!(bb.covers_point(*vertex));
+    }
+
+    // Grid clipping preserves the total area
+    let tiles = bb.clip_polygon_to_grid(diamond, 5, 4);
+    let total: f64 = tiles.iter().map(|(_, polygon)| area(polygon)).sum();
+    assert!((total - 82.0).abs() < 1e-9);
+    assert_eq!(tiles.len(), 20);
+    for ([col, row], polygon) in tiles.iter() {
+        let x = 2.0 * *col as f64;
+        let y = 2.5 * *row as f64;
+        let tile = BoundingBox::new(x, x + 2.0, y, y + 2.5);
+        for vertex in polygon.iter() {
+            assert!(tile.covers_point(*vertex));
+        }
+        assert_eq!(area(&tile.clip_polygon(diamond)), area(polygon));
+    }
+
+    // Polygon touching only one tile edge
+    let tiles = bb.clip_polygon_to_grid([[0.0, 0.0], [5.0, 0.0], [5.0, 5.0], [0.0, 5.0]], 2, 2);
+    assert_eq!(tiles.len(), 1);
+    assert_eq!(tiles[0].0, [0, 0]);
+    assert!(
+        bb.clip_polygon_to_grid([[20.0, 0.0], [25.0, 0.0], [25.0, 5.0]], 2, 2)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_transform() {
+    let boxes = pseudo_random_boxes(50);
+    let matrices = [
+        [[1.0, 0.0, 3.0], [0.0, 1.0, -2.0]],
+        [[0.5, -1.5, 0.0], [2.0, 0.25, 1.0]],
+        [[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        [[0.0, 0.0, 1.0], [0.0, 0.0, 2.0]],
+    ];
+    for bb in boxes.iter() {
+        let corners = [
+            [bb.xmin(), bb.ymin()],
+            [bb.xmax(), bb.ymin()],
+            [bb.xmin(), bb.ymax()],
+            [bb.xmax(), bb.ymax()],
+        ];
+        for m in matrices.iter() {
+            let expected = BoundingBox::from_points(corners.iter().map(|[x, y]| {
+                [
+                    m[0][0] * x + m[0][1] * y + m[0][2],
+                    m[1][0] * x + m[1][1] * y + m[1][2],
+                ]
+            }))
+            .unwrap();
+            assert!(bb.transform(*m).approx_eq(&expected, 1e-9, 0));
+        }
+
+        // Rotating by a full turn or mirroring twice reproduces the box
+        assert!(
+            bb.rotated(std::f64::consts::TAU, [3.0, 4.0])
+                .approx_eq(bb, 1e-9, 0)
+        );
+        assert_eq!(bb.mirrored_x(1.5).mirrored_x(1.5), *bb);
+        assert_eq!(bb.mirrored_y(-2.0).mirrored_y(-2.0), *bb);
+        assert_eq!(
+            bb.mirrored_x(0.0),
+            bb.transform([[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0]])
+        );
+
+        // Scaling about the center is equivalent to `scale`
+        let mut scaled = *bb;
+        scaled.scale_xy([3.0, 3.0], bb.center());
+        let mut expected = *bb;
+        expected.scale(3.0);
+        assert!(scaled.approx_eq(&expected, 1e-9, 0));
+    }
+
+    // Integer boxes
+    let mut bb = BoundingBox::new(0, 4, 0, 2);
+    bb.scale_xy([2, -1], [4, 0]);
+    assert_eq!(bb, BoundingBox::new(-4, 4, -2, 0));
+    assert_eq!(bb.mirrored_y(1), BoundingBox::new(-4, 4, 2, 4));
+
+    // Infinite boxes
+    let bb = BoundingBox::new(0.0, f64::INFINITY, 0.0, 1.0);
+    let swapped = bb.transform([[0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]);
+    assert_eq!(swapped, BoundingBox::new(0.0, 1.0, 0.0, f64::INFINITY));
+    let rotated = bb.rotated(0.1, [0.0, 0.0]);
+    assert_eq!(rotated.xmax(), f64::INFINITY);
+    assert_eq!(rotated.ymax(), f64::INFINITY);
+}
+
+#[test]
+fn test_oriented_bounding_box() {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    // Compare the SAT tests with a sampled reference
+    let sample_intersects = |a: &OrientedBoundingBox, b: &OrientedBoundingBox| {
+        let n = 60;
+        for (first, second) in [(a, b), (b, a)] {
+            let corners = first.corners();
+            for i in 0..4 {
+                let [p, q] = [corners[i], corners[(i + 1) % 4]];
+                for k in 0..=n {
+                    let t = k as f64 / n as f64;
+                    let point = [p[0] + t * (q[0] - p[0]), p[1] + t * (q[1] - p[1])];
+                    if second.covers_point(point) {
+                        return true;
+                    }
+                }
+            }
+        }
+        return false;
+    };
+    let boxes = pseudo_random_boxes(60);
+    let obbs: Vec<_> = boxes
+        .iter()
+        .enumerate()
+        .map(|(i, bb)| {
+            OrientedBoundingBox::new(
+                bb.center(),
+                [bb.width() / 2.0, bb.height() / 2.0],
+                i as f64 * 0.37,
+            )
+        })
+        .collect();
+    for a in obbs.iter() {
+        assert_eq!(BoundingBox::from(a), a.to_aabb());
+        for corner in a.corners() {
+            assert!(a.to_aabb().approx_covers_point(corner, 1e-9, 0));
+        }
+        for b in obbs.iter() {
+            assert_eq!(a.intersects(b), b.intersects(a));
+            if a.intersects(b) != sample_intersects(a, b) {
+                // Only near-touching configurations may differ from the sampling
+                let grown = OrientedBoundingBox::new(
+                    b.center(),
+                    [b.half_extents()[0] + 0.1, b.half_extents()[1] + 0.1],
+                    b.angle(),
+                );
+                assert!(sample_intersects(a, &grown));
+            }
+        }
+        for bb in boxes.iter() {
+            let as_obb = OrientedBoundingBox::try_from(*bb).unwrap();
+            assert_eq!(a.intersects_aabb(bb), a.intersects(&as_obb));
+        }
+    }
+
+    // Axis-aligned oriented boxes behave like bounding boxes
+    let a = OrientedBoundingBox::try_from(BoundingBox::new(0.0, 2.0, 0.0, 1.0)).unwrap();
+    let b = OrientedBoundingBox::try_from(BoundingBox::new(2.0, 3.0, 1.0, 2.0)).unwrap();
+    assert!(a.intersects(&b));
+    assert_eq!(a.area(), 2.0);
+    assert_eq!(a.to_aabb(), BoundingBox::new(0.0, 2.0, 0.0, 1.0));
+
+    // Non-finite input is rejected instead of producing a NaN center
+    let infinite = BoundingBox::new(f64::NEG_INFINITY, f64::INFINITY, 0.0, 1.0);
+    assert_eq!(
+        OrientedBoundingBox::try_from(infinite),
+        Err(BoundingBoxError::Infinite(Extremum::Xmin))
+    );
+    assert!(OrientedBoundingBox::try_new([0.0, 0.0], [f64::INFINITY, 1.0], 0.0).is_none());
+    assert!(OrientedBoundingBox::try_new([0.0, f64::NAN], [1.0, 1.0], 0.0).is_none());
+    assert!(OrientedBoundingBox::try_new([0.0, 0.0], [1.0, 1.0], f64::INFINITY).is_none());
+
+    // Deserialization validates the box
+    let obb = OrientedBoundingBox::new([1.0, 2.0], [3.0, 1.0], 0.5);
+    let json = serde_json::to_string(&obb).unwrap();
+    assert_eq!(
+        json,
+        r#"{"center":[1.0,2.0],"half_extents":[3.0,1.0],"angle":0.5}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<OrientedBoundingBox>(&json).unwrap(),
+        obb
+    );
+    let err = serde_json::from_str::<OrientedBoundingBox>(
+        r#"{"center":[0,0],"half_extents":[-1,1],"angle":0}"#,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("half-extents must not be negative"), "{err}");
+    assert!(
+        serde_json::from_str::<OrientedBoundingBox>(r#"{"center":[0,0],"half_extents":[1,1]}"#)
+            .is_err()
+    );
+
+    // Finite boxes whose width overflows are still converted
+    let wide = BoundingBox::new(-f64::MAX, f64::MAX, 0.0, 1.0);
+    let obb = OrientedBoundingBox::try_from(wide).unwrap();
+    assert_eq!(obb.center(), [0.0, 0.5]);
+    assert_eq!(obb.half_extents(), [f64::MAX, 0.5]);
+    assert_eq!(obb.to_aabb(), wide);
+    let degenerate = BoundingBox::new(f64::MAX, f64::MAX, 0.0, 1.0);
+    let obb = OrientedBoundingBox::try_from(degenerate).unwrap();
+    assert_eq!(obb.center(), [f64::MAX, 0.5]);
+    assert_eq!(obb.half_extents(), [0.0, 0.5]);
+    assert_eq!(obb.to_aabb(), degenerate);
+
+    // Rotated rectangle
+    let obb = OrientedBoundingBox::new([0.0, 0.0], [2.0, 1.0], FRAC_PI_2);
+    assert!(obb.covers_point([0.0, 1.9]));
+    assert!(!obb.covers_point([1.9, 0.0]));
+    let diamond = OrientedBoundingBox::new([0.0, 0.0], [1.0, 1.0], FRAC_PI_4);
+    assert!(!diamond.intersects_aabb(&BoundingBox::new(0.8, 2.0, 0.8, 2.0)));
+    assert!(diamond.intersects_aabb(&BoundingBox::new(0.7, 2.0, -0.1, 0.1)));
+
+    // Use with from_bounded_entities
+    let bb = BoundingBox::from_bounded_entities([&obb, &diamond].into_iter()).unwrap();
+    let r = 2.0f64.sqrt();
+    assert!(bb.approx_eq(&BoundingBox::new(-r, r, -2.0, 2.0), 1e-12, 0));
+
+    assert!(OrientedBoundingBox::try_new([0.0, 0.0], [f64::NAN, 1.0], 0.0).is_none());
+}
+
+#[test]
+fn test_minimum_rectangle() {
+    let all_points = pseudo_random_points(160);
+    for points in all_points.chunks(20) {
+        let min_area = OrientedBoundingBox::min_area_from_points(points.iter().copied()).unwrap();
+        let min_perimeter =
+            OrientedBoundingBox::min_perimeter_from_points(points.iter().copied()).unwrap();
+        for rect in [min_area, min_perimeter] {
+            assert!(rect.angle() >= 0.0 && rect.angle() < std::f64::consts::FRAC_PI_2);
+            let grown = OrientedBoundingBox::new(
+                rect.center(),
+                [rect.half_extents()[0] + 1e-9, rect.half_extents()[1] + 1e-9],
+                rect.angle(),
+            );
+            for point in points.iter() {
+                assert!(grown.covers_point(*point));
+            }
+        }
+
+        // Compare with a brute-force search over sampled angles
+        let n = 2000;
+        for k in 0..n {
+            let angle = std::f64::consts::FRAC_PI_2 * k as f64 / n as f64;
+            let (sin, cos) = angle.sin_cos();
+            let local = BoundingBox::from_points(
+                points
+                    .iter()
+                    .map(|[x, y]| [cos * x + sin * y, -sin * x + cos * y]),
+            )
+            .unwrap();
+            assert!(min_area.area() <= local.width() * local.height() + 1e-9);
+            assert!(min_perimeter.perimeter() <= 2.0 * (local.width() + local.height()) + 1e-9);
+        }
+        assert!(min_area.area() <= min_perimeter.area() + 1e-9);
+        assert!(min_perimeter.perimeter() <= min_area.perimeter() + 1e-9);
+    }
+
+    // Degenerate inputs
+    assert!(
+        OrientedBoundingBox::min_area_from_points(Vec::<[f64; 2]>::new().into_iter()).is_none()
+    );
+    let point = OrientedBoundingBox::min_area_from_points([[1.0, 2.0]; 3].into_iter()).unwrap();
+    assert_eq!(point.center(), [1.0, 2.0]);
+    assert_eq!(point.area(), 0.0);
+    let segment =
+        OrientedBoundingBox::min_area_from_points([[0.0, 0.0], [1.0, 1.0], [3.0, 3.0]].into_iter())
+            .unwrap();
+    assert_eq!(segment.area(), 0.0);
+    assert!((segment.width().max(segment.height()) - 18.0f64.sqrt()).abs() < 1e-12);
+    assert!((segment.center()[0] - 1.5).abs() < 1e-12);
+    let infinite = [[0.0, 0.0], [f64::INFINITY, 1.0], [1.0, 1.0]];
+    assert!(OrientedBoundingBox::min_area_from_points(infinite.into_iter()).is_none());
+    assert!(OrientedBoundingBox::min_perimeter_from_points(infinite.into_iter()).is_none());
+}
+
+#[test]
+fn test_convex_hull() {
+    let is_convex_ccw = |hull: &[[f64; 2]]| {
+        (0..hull.len()).all(|i| {
+            let [a, b, c] = [
+                hull[i],
+                hull[(i + 1) % hull.len()],
+                hull[(i + 2) % hull.len()],
+            ];
+            (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]) > 0.0
+        })
+    };
+
+    let points = pseudo_random_points(200);
+    let (bb, hull) = BoundingBox::from_points_with_hull(points.iter().copied()).unwrap();
+    assert_eq!(Some(bb), BoundingBox::from_points(points.iter().copied()));
+    assert_eq!(hull, convex_hull(points.iter().copied()));
+    assert!(hull.len() >= 3);
+    assert!(is_convex_ccw(&hull));
+
+    // All points lie on the inner side of every hull edge
+    for i in 0..hull.len() {
+        let [a, b] = [hull[i], hull[(i + 1) % hull.len()]];
+        for p in points.iter() {
+            assert!((b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0]) >= 0.0);
+        }
+    }
+    // The extremas of the hull match the bounding box
+    assert_eq!(BoundingBox::from_points(hull.iter().copied()), Some(bb));
+
+    // Grid of points with many collinear points and duplicates
+    let grid: Vec<[f64; 2]> = (0..25)
+        .flat_map(|i| [[(i % 5) as f64, (i / 5) as f64]; 2])
+        .collect();
+    assert_eq!(
+        convex_hull(grid.into_iter()),
+        vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]]
+    );
+
+    // Degenerate inputs
+    assert!(convex_hull(Vec::<[f64; 2]>::new().into_iter()).is_empty());
+    assert_eq!(convex_hull([[1.0, 2.0]; 4].into_iter()), vec![[1.0, 2.0]]);
+    assert_eq!(
+        convex_hull([[0.0, 0.0], [f64::NAN, 1.0], [1.0, 0.0]].into_iter()),
+        vec![[0.0, 0.0], [1.0, 0.0]]
+    );
+    assert!(BoundingBox::from_points_with_hull([[f64::NAN, 0.0]].into_iter()).is_none());
+}
+
+#[test]
+fn test_circle() {
+    // Minimum enclosing circle of pseudo-random point sets
+    let all_points = pseudo_random_points(300);
+    for points in all_points.chunks(30) {
+        let circle = Circle::min_enclosing(points.iter().copied()).unwrap();
+        let grown = Circle::new(circle.center(), circle.radius() * (1.0 + 1e-9));
+        let mut on_boundary = 0;
+        for point in points.iter() {
+            assert!(grown.covers_point(*point));
+            let dist = ((point[0] - circle.center()[0]).powi(2)
+                + (point[1] - circle.center()[1]).powi(2))
+            .sqrt();
+            if (dist - circle.radius()).abs() < 1e-9 {
+                on_boundary += 1;
+            }
+        }
+        // A minimum enclosing circle is defined by at least two points
+        assert!(on_boundary >= 2);
+        // ... and is not larger than the circumscribed circle of the bounding box
+        let bb = BoundingBox::from_points(points.iter().copied()).unwrap();
+        assert!(circle.radius() <= bb.circumscribed_circle().unwrap().radius() + 1e-9);
+        assert!(circle.radius() >= bb.inscribed_circle().unwrap().radius() - 1e-9);
+    }
+
+    // Degenerate inputs
+    assert!(Circle::min_enclosing(Vec::<[f64; 2]>::new().into_iter()).is_none());
+    let circle = Circle::min_enclosing([[1.0, 1.0]; 5].into_iter()).unwrap();
+    assert_eq!((circle.center(), circle.radius()), ([1.0, 1.0], 0.0));
+    let collinear = [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [4.0, 4.0], [3.0, 3.0]];
+    let circle = Circle::min_enclosing(collinear.into_iter()).unwrap();
+    assert_eq!(circle.center(), [2.0, 2.0]);
+    assert!((circle.radius() - 8.0f64.sqrt()).abs() < 1e-12);
+
+    // NaN points are skipped, infinite points yield no circle
+    let with_nan = [[0.0, 0.0], [f64::NAN, 1.0], [2.0, 0.0]];
+    let circle = Circle::min_enclosing(with_nan.into_iter()).unwrap();
+    assert_eq!((circle.center(), circle.radius()), ([1.0, 0.0], 1.0));
+    assert!(Circle::min_enclosing([[f64::NAN, f64::NAN]].into_iter()).is_none());
+    let with_inf = [[0.0, 0.0], [1.0, f64::NEG_INFINITY], [2.0, 0.0]];
+    assert!(Circle::min_enclosing(with_inf.into_iter()).is_none());
+
+    // Circle-box interaction
+    let circle = Circle::new([0.0, 0.0], 2.0);
+    for bb in pseudo_random_boxes(100).iter() {
+        let closest = bb.closest_point([0.0, 0.0]);
+        assert_eq!(circle.intersects_box(bb), circle.covers_point(closest));
+        let corners = [
+            [bb.xmin(), bb.ymin()],
+            [bb.xmax(), bb.ymin()],
+            [bb.xmin(), bb.ymax()],
+            [bb.xmax(), bb.ymax()],
+        ];
+        assert_eq!(
+            circle.covers_box(bb),
+            corners.iter().all(|c| circle.covers_point(*c))
+        );
+        let circumscribed = bb.circumscribed_circle().unwrap();
+        let grown = Circle::new(
+            circumscribed.center(),
+            circumscribed.radius() * (1.0 + 1e-12),
+        );
+        assert!(grown.covers_box(bb));
+        assert!(bb.covers_circle(&bb.inscribed_circle().unwrap()));
+    }
+    let bb = BoundingBox::new(-5.0, 5.0, -5.0, 5.0);
+    assert!(bb.covers_circle(&circle));
+    assert!(!circle.covers_box(&bb));
+    assert_eq!(
+        BoundingBox::from(&circle),
+        BoundingBox::new(-2.0, 2.0, -2.0, 2.0)
+    );
+
+    // Invalid circles can't be constructed
+    assert!(Circle::try_new([f64::NAN, 0.0], 1.0).is_none());
+    assert!(Circle::try_new([0.0, f64::INFINITY], 1.0).is_none());
+    let infinite = BoundingBox::new(f64::NEG_INFINITY, f64::INFINITY, 0.0, 1.0);
+    assert_eq!(infinite.inscribed_circle(), None);
+    assert_eq!(infinite.circumscribed_circle(), None);
+    let huge = Circle::new([0.0, 0.0], f64::INFINITY);
+    assert_eq!(
+        BoundingBox::from(&huge),
+        BoundingBox::new(
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY
+        )
+    );
+
+    // Points close to the limits of f64 still result in a circle
+    let extreme = [[-f64::MAX, 0.0], [f64::MAX, 0.0], [0.0, f64::MAX]];
+    let circle = Circle::min_enclosing(extreme.into_iter()).unwrap();
+    assert!(circle.center()[0].is_finite() && circle.center()[1].is_finite());
+
+    // Deserialization validates the circle
+    let circle = Circle::new([1.0, 2.0], 3.0);
+    let json = serde_json::to_string(&circle).unwrap();
+    assert_eq!(json, r#"{"center":[1.0,2.0],"radius":3.0}"#);
+    assert_eq!(serde_json::from_str::<Circle>(&json).unwrap(), circle);
+    for invalid in [
+        r#"{"center":[1.0,2.0],"radius":-3.0}"#,
+        r#"{"center":[1.0,2.0]}"#,
+    ] {
+        assert!(serde_json::from_str::<Circle>(invalid).is_err());
+    }
+    let err = serde_json::from_str::<Circle>(r#"{"center":[1.0,2.0],"radius":-3.0}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("radius must not be negative"), "{err}");
+}
+
+#[test]
+fn test_curve_bounding_boxes() {
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    // The bounding box must contain all sampled points and be tight
+    fn check<C: ToBoundingBox>(curve: &C, point_at: impl Fn(f64) -> [f64; 2]) {
+        let n = 20000;
+        let sampled =
+            BoundingBox::from_points((0..=n).map(|k| point_at(k as f64 / n as f64))).unwrap();
+        let bb = curve.bounding_box();
+        assert!(bb.approx_covers(&sampled, 1e-9, 0));
+        let tol = 1e-6 * (1.0 + bb.width().max(bb.height()));
+        assert!(sampled.approx_covers(&bb, tol, 0), "{bb:?} vs. {sampled:?}");
+    }
+
+    let points = pseudo_random_points(40);
+    for (i, w) in points.windows(5).enumerate() {
+        let [p0, p1, p2, p3] = [w[0], w[1], w[2], w[3]];
+        let angle = 0.7 * i as f64 - 5.0;
+        let sweep = (i as f64 * 1.3) % 8.0 - 4.0;
+
+        let segment = LineSegment::new(p0, p1);
+        check(&segment, |t| segment.point_at(t));
+        let quadratic = QuadraticBezier::new(p0, p1, p2);
+        check(&quadratic, |t| quadratic.point_at(t));
+        let cubic = CubicBezier::new(p0, p1, p2, p3);
+        check(&cubic, |t| cubic.point_at(t));
+
+        // Radii between 0.5 and 5.5 derived from the fifth point
+        let radii = [0.5 + w[4][0] / 20.0, 0.5 + w[4][1] / 20.0];
+        let ellipse = Ellipse::new(p0, radii, angle);
+        check(&ellipse, |t| ellipse.point_at(t * TAU));
+        let arc = EllipticalArc::new(p0, radii, angle, angle * 0.3, sweep);
+        check(&arc, |t| arc.point_at(t));
+        let arc = CircularArc::new(p1, radii[0], angle, sweep);
+        check(&arc, |t| arc.point_at(t));
+    }
+
+    // Full circle and degenerate curves
+    let arc = CircularArc::new([1.0, 1.0], 1.0, 0.3, -TAU);
+    assert_eq!(
+        BoundingBox::from(&arc),
+        BoundingBox::new(0.0, 2.0, 0.0, 2.0)
+    );
+    let arc = CircularArc::new([0.0, 0.0], 1.0, FRAC_PI_2, PI);
+    assert!(BoundingBox::from(&arc).approx_eq(&BoundingBox::new(-1.0, 0.0, -1.0, 1.0), 1e-12, 0));
+    let line = CubicBezier::new([0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]);
+    assert_eq!(
+        BoundingBox::from(&line),
+        BoundingBox::new(0.0, 3.0, 0.0, 3.0)
+    );
+    let point = QuadraticBezier::new([1.0, 1.0], [1.0, 1.0], [1.0, 1.0]);
+    assert_eq!(
+        BoundingBox::from(&point),
+        BoundingBox::new(1.0, 1.0, 1.0, 1.0)
+    );
+
+    // NaN values are rejected instead of being dropped from some boxes
+    let nan = f64::NAN;
+    let panics = |f: &dyn Fn() -> BoundingBox<f64>| {
+        return std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).is_err();
+    };
+    assert!(panics(
+        &|| LineSegment::new([0.0, 0.0], [nan, 1.0]).bounding_box()
+    ));
+    assert!(panics(
+        &|| LineSegment::new([nan, nan], [nan, nan]).bounding_box()
+    ));
+    assert!(panics(&|| {
+        QuadraticBezier::new([0.0, 0.0], [nan, 1.0], [2.0, 0.0]).bounding_box()
+    }));
+    assert!(panics(&|| {
+        CubicBezier::new([0.0, 0.0], [1.0, 1.0], [2.0, nan], [3.0, 0.0]).bounding_box()
+    }));
+    assert!(panics(
+        &|| Ellipse::new([nan, 0.0], [1.0, 1.0], 0.0).bounding_box()
+    ));
+    assert!(panics(&|| Ellipse::new(
+        [0.0, 0.0],
+        [1.0, 1.0],
+        f64::INFINITY
+    )
+    .bounding_box()));
+    assert!(panics(&|| {
+        EllipticalArc::new([0.0, 0.0], [1.0, 1.0], 0.0, 0.0, nan).bounding_box()
+    }));
+    assert!(panics(
+        &|| CircularArc::new([0.0, 0.0], nan, 0.0, PI).bounding_box()
+    ));
+    assert!(panics(&|| {
+        CircularArc::new([0.0, 0.0], 1.0, f64::INFINITY, PI).bounding_box()
+    }));
+
+    // Infinite coordinates are fine as long as they don't cancel out
+    let segment = LineSegment::new([0.0, 0.0], [f64::INFINITY, 1.0]);
+    assert_eq!(
+        segment.bounding_box(),
+        BoundingBox::new(0.0, f64::INFINITY, 0.0, 1.0)
+    );
+
+    // Bounding box of a path consisting of different curves
+    let path = [
+        LineSegment::new([0.0, 0.0], [2.0, 0.0]).bounding_box(),
+        CircularArc::new([2.0, 1.0], 1.0, -FRAC_PI_2, PI).bounding_box(),
+        CubicBezier::new([2.0, 2.0], [1.0, 4.0], [0.0, 0.0], [-1.0, 2.0]).bounding_box(),
+    ];
+    let bb = BoundingBox::from_bounded_entities(path.into_iter()).unwrap();
+    assert_eq!(bb.xmin(), -1.0);
+    assert_eq!(bb.xmax(), 3.0);
+    assert_eq!(bb.ymin(), 0.0);
+    assert!(bb.ymax() > 2.0 && bb.ymax() < 4.0);
+}
+
+#[test]
+fn test_path() {
+    use std::f64::consts::PI;
+
+    let mut path = Path::new();
+    assert!(path.is_empty());
+    assert_eq!(path.bounds(), None);
+
+    path.push(LineSegment::new([0.0, 0.0], [4.0, 0.0]));
+    path.push(CubicBezier::new(
+        [4.0, 0.0],
+        [6.0, 0.0],
+        [6.0, 4.0],
+        [4.0, 4.0],
+    ));
+    path.push(CircularArc::new([2.0, 4.0], 2.0, 0.0, PI));
+    path.push(QuadraticBezier::new([0.0, 4.0], [-1.0, 2.0], [0.0, 0.0]));
+    assert_eq!(path.len(), 4);
+
+    // The path box is the union of the cached segment boxes
+    let expected = BoundingBox::from_bounded_entities(path.segments().iter()).unwrap();
+    assert_eq!(path.bounding_box(), expected);
+    for (idx, segment) in path.segments().iter().enumerate() {
+        assert_eq!(path.segment_box(idx), Some(segment.bounding_box()));
+    }
+
+    // Query using the cached boxes
+    let query = BoundingBox::new(5.0, 6.0, 1.0, 3.0);
+    let hits: Vec<usize> = path
+        .query_intersecting(&query)
+        .map(|(idx, _)| idx)
+        .collect();
+    assert_eq!(hits, vec![1]);
+    let query = BoundingBox::new(-10.0, 10.0, 3.0, 10.0);
+    let hits: Vec<usize> = path
+        .query_intersecting(&query)
+        .map(|(idx, _)| idx)
+        .collect();
+    assert_eq!(hits, vec![1, 2, 3]);
+
+    // Moving a segment inwards shrinks the path box, moving it outwards grows it
+    path.translate_segment(2, [0.0, -3.0]);
+    let expected = BoundingBox::from_bounded_entities(path.segments().iter()).unwrap();
+    assert!(path.bounding_box().approx_eq(&expected, 1e-12, 0));
+    path.translate_segment(2, [0.0, 10.0]);
+    let expected = BoundingBox::from_bounded_entities(path.segments().iter()).unwrap();
+    assert!(path.bounding_box().approx_eq(&expected, 1e-12, 0));
+    assert!(
+        path.segment_box(2)
+            .unwrap()
+            .approx_eq(&path.get(2).unwrap().bounding_box(), 1e-12, 0)
+    );
+
+    // Replacing a segment
+    let old = path.set(2, LineSegment::new([4.0, 4.0], [0.0, 4.0]));
+    assert!(matches!(old, PathSegment::Arc(_)));
+    let expected = BoundingBox::from_bounded_entities(path.segments().iter()).unwrap();
+    assert_eq!(path.bounding_box(), expected);
+
+    // Translating the whole path
+    let before = path.bounding_box();
+    path.translate([1.0, -1.0]);
+    let mut shifted = before;
+    shifted.translate([1.0, -1.0]);
+    assert_eq!(path.bounding_box(), shifted);
+
+    // Removing segments
+    while path.pop().is_some() {
+        if let Some(bb) = path.bounds() {
+            let expected = BoundingBox::from_bounded_entities(path.segments().iter()).unwrap();
+            assert_eq!(bb, expected);
+        }
+    }
+    assert_eq!(path.bounds(), None);
+}
+
+#[test]
+fn test_serde() {
+    use serde::{Deserialize, Serialize};
+
+    // Default representation roundtrip
+    let bb = BoundingBox::new(-1.5, 2.0, 0.0, 3.25);
+    let json = serde_json::to_string(&bb).unwrap();
+    assert_eq!(json, r#"{"xmin":-1.5,"xmax":2.0,"ymin":0.0,"ymax":3.25}"#);
+    assert_eq!(serde_json::from_str::<BoundingBox>(&json).unwrap(), bb);
+
+    let bb_i32 = BoundingBox::new(0, 640, 0, 480);
+    let json = serde_json::to_string(&bb_i32).unwrap();
+    assert_eq!(
+        serde_json::from_str::<BoundingBox<i32>>(&json).unwrap(),
+        bb_i32
+    );
+
+    // Invalid input is rejected with a descriptive message
+    let err = serde_json::from_str::<BoundingBox>(r#"{"xmin":0,"xmax":1,"ymin":3,"ymax":2}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("ymin (3) is larger than ymax (2)"), "{err}");
+    let err = serde_json::from_str::<BoundingBox<i64>>(r#"{"xmin":5,"xmax":1,"ymin":0,"ymax":2}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("xmin (5) is larger than xmax (1)"), "{err}");
+    assert!(serde_json::from_str::<BoundingBox>(r#"{"xmin":0,"xmax":1,"ymin":0}"#).is_err());
+
+    // The three-dimensional box is validated as well
+    let bb3 = BoundingBox3::new(0.0, 1.0, -1.0, 1.0, 2.0, 3.0);
+    let json = serde_json::to_string(&bb3).unwrap();
+    assert_eq!(serde_json::from_str::<BoundingBox3>(&json).unwrap(), bb3);
+    let err = serde_json::from_str::<BoundingBox3>(
+        r#"{"xmin":5,"xmax":1,"ymin":0,"ymax":1,"zmin":0,"zmax":1}"#,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("xmin <= xmax"), "{err}");
+
+    // NaN can't be represented in JSON, so use a serde value deserializer instead
+    use serde::de::IntoDeserializer;
+    use serde::de::value::{Error, SeqDeserializer};
+    let seq: SeqDeserializer<_, Error> = vec![0.0, 0.0, f64::NAN, 1.0].into_deserializer();
+    let err = bounding_box::representation::array::deserialize::<f64, _>(seq)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("xmax is NaN"), "{err}");
+
+    // Alternative representations
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Annotation {
+        #[serde(with = "bounding_box::representation::array")]
+        array: BoundingBox,
+        #[serde(with = "bounding_box::representation::corners")]
+        corners: BoundingBox<i32>,
+        #[serde(with = "bounding_box::representation::xywh")]
+        xywh: BoundingBox,
+        #[serde(with = "bounding_box::representation::xywh")]
+        pixels: BoundingBox<i32>,
+    }
+
+    let annotation = Annotation {
+        array: BoundingBox::new(1.0, 2.0, 3.0, 4.0),
+        corners: BoundingBox::new(-1, 1, -2, 2),
+        xywh: BoundingBox::new(0.5, 2.5, -1.0, 1.0),
+        pixels: BoundingBox::new(10, 30, 0, 20),
+    };
+    let json = serde_json::to_string(&annotation).unwrap();
+    assert_eq!(
+        json,
+        r#"{"array":[1.0,3.0,2.0,4.0],"corners":{"min":[-1,-2],"max":[1,2]},"xywh":{"x":0.5,"y":-1.0,"width":2.0,"height":2.0},"pixels":{"x":10,"y":0,"width":20,"height":20}}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Annotation>(&json).unwrap(),
+        annotation
+    );
+
+    let invalid = [
+        (
+            r#"{"array":[2.0,3.0,1.0,4.0],"corners":{"min":[-1,-2],"max":[1,2]},"xywh":{"x":0,"y":0,"width":1,"height":1},"pixels":{"x":0,"y":0,"width":1,"height":1}}"#,
+            "xmin (2) is larger than xmax (1)",
+        ),
+        (
+            r#"{"array":[1.0,3.0,2.0,4.0],"corners":{"min":[-1,3],"max":[1,2]},"xywh":{"x":0,"y":0,"width":1,"height":1},"pixels":{"x":0,"y":0,"width":1,"height":1}}"#,
+            "ymin (3) is larger than ymax (2)",
+        ),
+        (
+            r#"{"array":[1.0,3.0,2.0,4.0],"corners":{"min":[-1,-2],"max":[1,2]},"xywh":{"x":0,"y":0,"width":-1,"height":1},"pixels":{"x":0,"y":0,"width":1,"height":1}}"#,
+            "width (-1) is negative",
+        ),
+        (
+            r#"{"array":[1.0,3.0,2.0,4.0],"corners":{"min":[-1,-2],"max":[1,2]},"xywh":{"x":0,"y":0,"width":1,"height":1},"pixels":{"x":2147483647,"y":0,"width":1,"height":1}}"#,
+            "x + width overflows",
+        ),
+    ];
+    for (json, message) in invalid {
+        let err = serde_json::from_str::<Annotation>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(message), "{err}");
+    }
+
+    // Sizes which can't be represented are a serialization error
+    let overflowing = Annotation {
+        pixels: BoundingBox::new(i32::MIN, i32::MAX, 0, 1),
+        ..annotation
+    };
+    let err = serde_json::to_string(&overflowing).unwrap_err().to_string();
+    assert!(err.contains("width overflows"), "{err}");
+}
+
+#[test]
+fn test_bounding_box_error() {
+    use std::error::Error;
+
+    // The error can be used with `?` in functions returning a boxed error
+    fn parse(values: [f64; 4]) -> Result<BoundingBox, Box<dyn Error>> {
+        let bb = BoundingBox::new_checked(values[0], values[1], values[2], values[3])?;
+        return Ok(bb);
+    }
+    assert_eq!(
+        parse([0.0, 1.0, 0.0, 1.0]).unwrap(),
+        BoundingBox::new(0.0, 1.0, 0.0, 1.0)
+    );
+    assert_eq!(
+        parse([0.0, -1.0, 0.0, 1.0]).unwrap_err().to_string(),
+        "invalid bounding box: xmin (0) is larger than xmax (-1)"
+    );
+
+    // NaN takes precedence over inverted extremas
+    assert_eq!(
+        BoundingBox::new_checked(2.0, 1.0, f64::NAN, 1.0),
+        Err(BoundingBoxError::NaN(Extremum::Ymin))
+    );
+    assert_eq!(
+        BoundingBox::new_checked(2.0f32, 1.0, 4.0, 1.0),
+        Err(BoundingBoxError::InvertedX {
+            xmin: 2.0,
+            xmax: 1.0
+        })
+    );
+    assert_eq!(
+        BoundingBox::new_finite_checked(f64::NEG_INFINITY, 1.0, 0.0, 1.0),
+        Err(BoundingBoxError::Infinite(Extremum::Xmin))
+    );
+    assert!(BoundingBox::new_finite_checked(i32::MIN, i32::MAX, 0, 1).is_ok());
+    assert_eq!(
+        BoundingBoxError::Infinite(Extremum::Xmax).to_string(),
+        "invalid bounding box: xmax is infinite"
+    );
+
+    // Panic message of `new`
+    let message = std::panic::catch_unwind(|| BoundingBox::new(0.0, 1.0, 5.0, 2.0))
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    assert_eq!(
+        *message,
+        "invalid bounding box: ymin (5) is larger than ymax (2)"
+    );
+
+    // Checked setters leave the box unchanged on failure
+    let mut bb = BoundingBox::new(0, 10, 0, 10);
+    assert_eq!(
+        bb.set_ymin_checked(11),
+        Err(BoundingBoxError::InvertedY {
+            ymin: 11.0,
+            ymax: 10.0
+        })
+    );
+    assert_eq!(
+        bb.set_ymax_checked(-1),
+        Err(BoundingBoxError::InvertedY {
+            ymin: 0.0,
+            ymax: -1.0
+        })
+    );
+    assert_eq!(bb, BoundingBox::new(0, 10, 0, 10));
+    assert!(bb.set_xmin_checked(10).is_ok());
+    assert!(bb.set_xmax_checked(10).is_ok());
+    assert_eq!(bb, BoundingBox::new(10, 10, 0, 10));
+
+    let mut bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    assert!(!bb.try_set_ymax(f64::NAN));
+    assert_eq!(bb.ymax(), 1.0);
+}
+
+#[test]
+fn test_nan_policy() {
+    let nan = f64::NAN;
+
+    // Constructors and setters reject NaN
+    assert!(BoundingBox::try_new(nan, 1.0, 0.0, 1.0).is_none());
+    assert!(BoundingBox::try_new(0.0, nan, 0.0, 1.0).is_none());
+    assert!(BoundingBox::try_new(0.0, 1.0, nan, 1.0).is_none());
+    assert!(BoundingBox::try_new(0.0, 1.0, 0.0, nan).is_none());
+    assert!(std::panic::catch_unwind(|| BoundingBox::new(nan, 1.0, 0.0, 1.0)).is_err());
+    assert!(std::panic::catch_unwind(|| BoundingBox::from([nan, 1.0])).is_err());
+    let mut bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    assert!(!bb.try_set_xmin(nan));
+    assert!(!bb.try_set_xmax(nan));
+    assert!(!bb.try_set_ymin(nan));
+    assert!(!bb.try_set_ymax(nan));
+    assert_eq!(bb, BoundingBox::new(0.0, 1.0, 0.0, 1.0));
+
+    // from_points: NaN at any position is skipped or reported
+    for position in 0..4 {
+        let mut verts = vec![[0.0, 0.0], [1.0, 2.0], [-1.0, 1.0]];
+        verts.insert(position, [5.0, nan]);
+        assert_eq!(
+            BoundingBox::from_points(verts.iter().copied()),
+            Some(BoundingBox::new(-1.0, 1.0, 0.0, 2.0))
+        );
+        assert_eq!(
+            BoundingBox::from_points_checked(verts.iter().copied()),
+            Err(BoundingBoxError::NaNInput { index: position })
+        );
+    }
+    assert_eq!(
+        BoundingBox::from_points([[nan, 0.0], [0.0, nan]].into_iter()),
+        None
+    );
+
+    // The same applies to the three-dimensional box
+    for idx in 0..6 {
+        let mut extremas = [0.0, 1.0, 0.0, 1.0, 0.0, 1.0];
+        extremas[idx] = nan;
+        let [xmin, xmax, ymin, ymax, zmin, zmax] = extremas;
+        assert!(BoundingBox3::try_new(xmin, xmax, ymin, ymax, zmin, zmax).is_none());
+    }
+    assert!(std::panic::catch_unwind(|| BoundingBox3::new(nan, 1.0, 0.0, 1.0, 0.0, 1.0)).is_err());
+    assert_eq!(
+        BoundingBox3::from_points([[nan, 0.0, 0.0], [1.0, 2.0, 3.0], [0.0, 0.0, 0.0]].into_iter()),
+        Some(BoundingBox3::new(0.0, 1.0, 0.0, 2.0, 0.0, 3.0))
+    );
+    assert_eq!(
+        BoundingBox3::from_points([[0.0, nan, 0.0]].into_iter()),
+        None
+    );
+    let unit3 = BoundingBox3::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+    let mut nan_box3 = BoundingBox3::new(1.0, 2.0, 1.0, 2.0, 0.0, 1.0);
+    nan_box3.translate([nan, 0.0, 0.0]);
+    assert_eq!(
+        BoundingBox3::from_bounded_entities([nan_box3, unit3, nan_box3].iter()),
+        Some(unit3)
+    );
+    assert_eq!(BoundingBox3::from_bounded_entities([nan_box3].iter()), None);
+    for (first, second) in [(&unit3, &nan_box3), (&nan_box3, &unit3)] {
+        assert!(!first.touches(second));
+        assert!(!first.approx_touches(second, 1e-3, 0));
+        assert!(!first.intersects(second));
+    }
+
+    // from_bounded_entities: NaN boxes are skipped or reported
+    let mut nan_box = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    nan_box.scale(nan);
+    for position in 0..3 {
+        let mut boxes = vec![
+            BoundingBox::new(0.0, 1.0, 0.0, 1.0),
+            BoundingBox::new(2.0, 3.0, 0.5, 4.0),
+        ];
+        boxes.insert(position, nan_box);
+        assert_eq!(
+            BoundingBox::from_bounded_entities(boxes.iter()),
+            Some(BoundingBox::new(0.0, 3.0, 0.0, 4.0))
+        );
+        assert_eq!(
+            BoundingBox::from_bounded_entities_checked(boxes.iter()),
+            Err(BoundingBoxError::NaNInput { index: position })
+        );
+    }
+
+    // Point predicates are false for NaN coordinates
+    let bb = BoundingBox::new(0.0, 1.0, 0.0, 1.0);
+    for point in [[nan, 0.5], [0.5, nan], [nan, nan]] {
+        assert!(!bb.covers_point(point));
+        assert!(!bb.contains_point(point));
+        assert!(!bb.approx_covers_point(point, 1e-3, 0));
+    }
+
+    // Box predicates are false if any extremum of either box is NaN
+    let mut nan_boxes = Vec::new();
+    for idx in 0..4 {
+        let mut nan_box = BoundingBox::new(-10.0, 10.0, -10.0, 10.0);
+        match idx {
+            0 => nan_box.translate([nan, 0.0]),
+            1 => nan_box.translate([0.0, nan]),
+            2 => nan_box.scale_xy([nan, 1.0], [0.0, 0.0]),
+            _ => nan_box = nan_box.mirrored_y(nan),
+        }
+        nan_boxes.push(nan_box);
+    }
+    let touching = BoundingBox::new(-10.0, 10.0, 10.0, 20.0);
+    for nan_box in nan_boxes.iter() {
+        for other in [bb, touching, *nan_box] {
+            for (first, second) in [(nan_box, &other), (&other, nan_box)] {
+                assert!(!first.covers(second));
+                assert!(!first.contains(second));
+                assert!(!first.intersects(second));
+                assert!(!first.overlaps(second));
+                assert!(!first.touches(second));
+                assert!(!first.approx_covers(second, 1e-3, 0));
+                assert!(!first.approx_touches(second, 1e-3, 0));
+                assert!(!first.approx_eq(second, 1e-3, 0));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_maybe_empty_box() {
+    let empty = MaybeEmptyBox::<f64>::EMPTY;
+    assert!(empty.is_empty());
+    assert_eq!(empty.get(), None);
+    assert_eq!(MaybeEmptyBox::<f64>::default(), empty);
+
+    // Identity and absorbing element
+    for bb in pseudo_random_boxes(20) {
+        let bb = MaybeEmptyBox::from(bb);
+        assert_eq!(bb.union(&empty), bb);
+        assert_eq!(empty.union(&bb), bb);
+        assert!(bb.intersection(&empty).is_empty());
+        assert!(empty.intersection(&bb).is_empty());
+    }
+    assert!(empty.union(&empty).is_empty());
+
+    // Folding with extend_point yields the same result as from_points
+    let points = [[1.0, -2.0], [3.5, 0.0], [-0.5, 4.0], [f64::NAN, 10.0]];
+    let mut bounds = MaybeEmptyBox::default();
+    for point in points {
+        bounds.extend_point(point);
+    }
+    assert_eq!(bounds.get(), BoundingBox::from_points(points.into_iter()));
+
+    // Sum is the union and matches from_bounded_entities
+    let boxes = pseudo_random_boxes(50);
+    let union: MaybeEmptyBox = boxes.iter().sum();
+    assert_eq!(
+        union.get(),
+        BoundingBox::from_bounded_entities(boxes.iter())
+    );
+    let union_of_parts: MaybeEmptyBox = boxes
+        .chunks(7)
+        .map(|chunk| chunk.iter().sum::<MaybeEmptyBox>())
+        .chain(std::iter::once(MaybeEmptyBox::EMPTY))
+        .sum();
+    assert_eq!(union_of_parts, union);
+
+    // Disjoint boxes have an empty intersection
+    let bb1 = MaybeEmptyBox::from(BoundingBox::new(0, 1, 0, 1));
+    let bb2 = MaybeEmptyBox::from(BoundingBox::new(2, 3, 0, 1));
+    assert!(bb1.intersection(&bb2).is_empty());
+    let option: Option<BoundingBox<i32>> = bb1.union(&bb2).into();
+    assert_eq!(option, Some(BoundingBox::new(0, 3, 0, 1)));
+
+    // Serialization
+    let json = serde_json::to_string(&empty).unwrap();
+    assert_eq!(json, "null");
+    assert_eq!(serde_json::from_str::<MaybeEmptyBox>(&json).unwrap(), empty);
+    let json = serde_json::to_string(&union).unwrap();
+    assert_eq!(serde_json::from_str::<MaybeEmptyBox>(&json).unwrap(), union);
+}
+
+#[test]
+fn test_operators_and_iterators() {
+    let boxes = pseudo_random_boxes(30);
+
+    // Operators mirror the named methods
+    for pair in boxes.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        assert_eq!(a | b, a.union(&b));
+        assert_eq!(a & b, a.intersection(&b));
+
+        let mut c = a;
+        c |= b;
+        assert_eq!(c, a | b);
+
+        let mut translated = a;
+        translated.translate([1.5, -2.0]);
+        assert_eq!(a + [1.5, -2.0], translated);
+        let mut c = a;
+        c += [1.5, -2.0];
+        assert_eq!(c, translated);
+
+        let mut scaled = a;
+        scaled.scale(0.5);
+        assert_eq!(a * 0.5, scaled);
+        let mut c = a;
+        c *= 0.5;
+        assert_eq!(c, scaled);
+
+        let (ma, mb) = (MaybeEmptyBox::from(a), MaybeEmptyBox::from(b));
+        assert_eq!(ma | mb, ma.union(&mb));
+        assert_eq!(ma & mb, MaybeEmptyBox::from(a & b));
+        let mut mc = ma;
+        mc &= MaybeEmptyBox::EMPTY;
+        assert!(mc.is_empty());
+        mc |= mb;
+        assert_eq!(mc, mb);
+    }
+    assert_eq!(
+        BoundingBox::new(0, 4, 0, 2) * 2,
+        BoundingBox::new(-2, 6, -1, 3)
+    );
+
+    // Sum is the union
+    let union: Option<BoundingBox> = boxes.iter().sum();
+    assert_eq!(union, BoundingBox::from_bounded_entities(boxes.iter()));
+    let union_by_value: Option<BoundingBox> = boxes.clone().into_iter().sum();
+    assert_eq!(union_by_value, union);
+    let nothing: Option<BoundingBox> = boxes.iter().take(0).sum();
+    assert_eq!(nothing, None);
+
+    // FromIterator and Extend reuse from_points
+    let points: Vec<[f64; 2]> = (0..20)
+        .map(|i| {
+            let t = i as f64;
+            [t.sin() * t, t.cos() * 0.5 * t]
+        })
+        .collect();
+    let collected: MaybeEmptyBox = points.iter().copied().collect();
+    assert_eq!(
+        collected.get(),
+        BoundingBox::from_points(points.iter().copied())
+    );
+    let empty: MaybeEmptyBox = std::iter::empty::<[f64; 2]>().collect();
+    assert!(empty.is_empty());
+
+    let mut bb = BoundingBox::from(points[0]);
+    bb.extend(points[1..].iter().copied());
+    assert_eq!(Some(bb), collected.get());
+
+    let mut extended = MaybeEmptyBox::EMPTY;
+    extended.extend(points.iter().copied());
+    extended.extend([[f64::NAN, 1000.0]]);
+    assert_eq!(extended, collected);
+}